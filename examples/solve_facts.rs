@@ -0,0 +1,35 @@
+//! Solves a directory of `.facts` files, such as the ones produced by
+//! rustc's `-Znll-facts`, and prints the errors.
+//!
+//! ```text
+//! cargo run --example solve_facts -- nll-facts/main/
+//! ```
+
+extern crate nll_souffle;
+
+use nll_souffle::{solve, tab_delim, InternerTables};
+use std::env;
+use std::path::Path;
+
+fn main() {
+    let mut tables = InternerTables::new();
+    for facts_dir in env::args().skip(1) {
+        let facts_dir = Path::new(&facts_dir);
+        let all_facts = if facts_dir.join("cfg_edge.facts").exists() {
+            tab_delim::load_rustc_nll_facts(&mut tables, facts_dir)
+        } else {
+            tab_delim::load_tab_delimited_facts(&mut tables, facts_dir)
+        };
+        let all_facts = all_facts.expect("invalid facts");
+
+        let output = solve(&all_facts);
+        println!("{}: {} errors", facts_dir.display(), output.errors.len());
+        for &(borrow, point) in &output.errors {
+            println!(
+                "  borrow {} conflicts with access at {}",
+                tables.borrows.untern(borrow),
+                tables.points.untern(point),
+            );
+        }
+    }
+}
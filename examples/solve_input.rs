@@ -0,0 +1,44 @@
+//! Solves an input written in the input language, and prints where
+//! each borrow is live.
+
+extern crate nll_souffle;
+
+use nll_souffle::parser::InputParser;
+use nll_souffle::{solve, InternerTables};
+
+const INPUT: &str = r#"
+block B0 {
+  statement {
+    borrow(B_x as B_x)
+    post(B_x: X)
+  }
+  statement {
+    liveOnEntry(X)
+  }
+  goto { B1 }
+}
+
+block B1 {
+  statement {
+    liveOnEntry(X)
+  }
+}
+"#;
+
+fn main() {
+    let ir = InputParser::new().parse(INPUT).expect("invalid input");
+
+    // Lowering interns the names of regions, borrows and points, so we
+    // need the tables to make sense of the results.
+    let mut tables = InternerTables::new();
+    let all_facts = solve::input_facts(&ir, &mut tables);
+    let output = solve(&all_facts);
+
+    for &(borrow, point) in &output.borrow_live_at {
+        println!(
+            "{} is live at {}",
+            tables.borrows.untern(borrow),
+            tables.points.untern(point),
+        );
+    }
+}
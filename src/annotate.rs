@@ -0,0 +1,86 @@
+//! An annotated listing of an input, which is easier to read than the
+//! flat list of results for larger inputs: the effects of each
+//! statement (and each terminator) are printed below the borrows and
+//! regions that are live at its point.
+
+use nll_souffle::intern::InternerTables;
+use nll_souffle::ir::*;
+use nll_souffle::solve::Output;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+crate struct Annotated<'a> {
+    input: Input,
+    live_borrows: BTreeMap<&'a str, BTreeSet<&'a str>>,
+    live_regions: BTreeMap<&'a str, BTreeSet<&'a str>>,
+}
+
+impl Annotated<'a> {
+    /// Annotates `input` with the results in `output`, which must have
+    /// been computed from it with `tables`.
+    crate fn new(input: &Input, tables: &'a InternerTables, output: &Output) -> Self {
+        let mut live_borrows = BTreeMap::new();
+        for &(borrow, point) in &output.borrow_live_at {
+            live_borrows
+                .entry(tables.points.untern(point))
+                .or_insert_with(BTreeSet::new)
+                .insert(tables.borrows.untern(borrow));
+        }
+
+        let mut live_regions = BTreeMap::new();
+        for &(region, point) in &output.region_live_at {
+            live_regions
+                .entry(tables.points.untern(point))
+                .or_insert_with(BTreeSet::new)
+                .insert(tables.regions.untern(region));
+        }
+
+        let mut input = input.clone();
+        input.sort_effects();
+        Annotated { input, live_borrows, live_regions }
+    }
+
+    /// E.g., `B0/1: borrows { B_x }, regions { X Y }`.
+    fn annotation(&self, point: &str) -> String {
+        let names = |live: &BTreeMap<&str, BTreeSet<&str>>| -> String {
+            match live.get(point) {
+                Some(names) if !names.is_empty() => {
+                    let names: Vec<&str> = names.iter().cloned().collect();
+                    format!("{{ {} }}", names.join(" "))
+                }
+                _ => "{ }".to_string(),
+            }
+        };
+        format!(
+            "{}: borrows {}, regions {}",
+            point,
+            names(&self.live_borrows),
+            names(&self.live_regions)
+        )
+    }
+}
+
+impl fmt::Display for Annotated<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (index, block) in self.input.blocks.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "block {} {{", block.name)?;
+            for (index, statement) in block.statements.iter().enumerate() {
+                writeln!(f, "  {}", self.annotation(&format!("{}/{}", block.name, index)))?;
+                for effect in &statement.effects {
+                    writeln!(f, "    {}", effect)?;
+                }
+            }
+
+            let term_point = format!("{}/{}", block.name, block.statements.len());
+            writeln!(f, "  {}", self.annotation(&term_point))?;
+            if block.terminator != Terminator::default() {
+                writeln!(f, "    {}", block.terminator)?;
+            }
+            writeln!(f, "}}")?;
+        }
+        Ok(())
+    }
+}
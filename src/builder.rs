@@ -0,0 +1,288 @@
+//! Constructs inputs programmatically, without going through the
+//! input language:
+//!
+//! ```ignore
+//! let ir = InputBuilder::new()
+//!     .block("B0")
+//!     .stmt(|s| s.borrow("B", "R").post("R", "X"))
+//!     .goto(&["B1"])
+//!     .block("B1")
+//!     .stmt(|s| s.live("X"))
+//!     .build()?;
+//! ```
+//!
+//! The result can be printed in the input language (`ir::Input`
+//! implements `Display`), or lowered into facts with `build_facts`.
+//! Misusing the builder (say, calling `stmt` before any `block`) is
+//! reported by `build`, as are inputs that are not valid.
+
+use facts::AllFacts;
+use intern::InternerTables;
+use ir::*;
+use solve;
+
+pub struct InputBuilder {
+    input: Input,
+    /// The first misuse of the builder, if any.
+    error: Option<String>,
+}
+
+impl InputBuilder {
+    pub fn new() -> Self {
+        InputBuilder {
+            input: Input {
+                comments: vec![],
+                signatures: vec![],
+                variables: vec![],
+                blocks: vec![],
+            },
+            error: None,
+        }
+    }
+
+    /// Declares a variable whose type has the regions `regions`; see
+    /// `ir::Variable`.
+    pub fn var(mut self, name: &str, regions: &[&str]) -> Self {
+        self.input.variables.push(Variable {
+            name: name.to_string(),
+            regions: regions
+                .iter()
+                .map(|region| VariableRegion {
+                    region: region.to_string(),
+                    may_dangle: false,
+                })
+                .collect(),
+        });
+        self
+    }
+
+    /// Marks the region `region` of the last declared variable as
+    /// `may_dangle`.
+    pub fn may_dangle(mut self, region: &str) -> Self {
+        let error = match self.input.variables.last_mut() {
+            Some(variable) => match variable.regions.iter_mut().find(|r| r.region == region) {
+                Some(variable_region) => {
+                    variable_region.may_dangle = true;
+                    None
+                }
+                None => Some(format!(
+                    "`may_dangle`: variable `{}` has no region `{}`",
+                    variable.name, region
+                )),
+            },
+            None => Some("`may_dangle` called before `var`".to_string()),
+        };
+        if let Some(error) = error {
+            self.fail(error);
+        }
+        self
+    }
+
+    /// Declares the signature of a function that can then be called
+    /// with `StatementBuilder::call`.
+    pub fn signature(mut self, signature: Signature) -> Self {
+        self.input.signatures.push(signature);
+        self
+    }
+
+    /// Starts a new block; the following calls to `stmt`, `goto` and
+    /// `terminator` apply to it.
+    pub fn block(mut self, name: &str) -> Self {
+        self.input.blocks.push(Block {
+            name: name.to_string(),
+            statements: vec![],
+            trailing_comments: vec![],
+            terminator: Terminator::default(),
+        });
+        self
+    }
+
+    /// Appends a statement to the current block, with the effects
+    /// added by `effects`.
+    pub fn stmt(mut self, effects: impl FnOnce(StatementBuilder) -> StatementBuilder) -> Self {
+        let statement = effects(StatementBuilder { effects: vec![] });
+        if let Some(block) = self.current_block("stmt") {
+            block.statements.push(Statement {
+                comments: vec![],
+                effects: statement.effects,
+            });
+        }
+        self
+    }
+
+    /// Adds edges from the end of the current block to `targets`, which
+    /// cannot be combined with another terminator.
+    pub fn goto(mut self, targets: &[&str]) -> Self {
+        let error = match self.current_block("goto") {
+            Some(block) => match &mut block.terminator {
+                Terminator::Goto { targets: goto } => {
+                    goto.extend(targets.iter().map(|target| target.to_string()));
+                    None
+                }
+                terminator => Some(format!(
+                    "`goto` in `{}` cannot be combined with `{}`",
+                    block.name, terminator
+                )),
+            },
+            None => None,
+        };
+        if let Some(error) = error {
+            self.fail(error);
+        }
+        self
+    }
+
+    /// Ends the current block with `terminator`, e.g. a `return` or a
+    /// call with an unwind edge.
+    pub fn terminator(mut self, terminator: Terminator) -> Self {
+        if let Some(block) = self.current_block("terminator") {
+            block.terminator = terminator;
+        }
+        self
+    }
+
+    /// The block started by the last call to `block`; `method` is the
+    /// method that needs it, for the error if there is none.
+    fn current_block(&mut self, method: &str) -> Option<&mut Block> {
+        if self.input.blocks.is_empty() {
+            self.fail(format!("`{}` called before `block`", method));
+            return None;
+        }
+        self.input.blocks.last_mut()
+    }
+
+    /// Records `error`, unless an earlier one was recorded already.
+    fn fail(&mut self, error: String) {
+        if self.error.is_none() {
+            self.error = Some(error);
+        }
+    }
+
+    /// Validates and returns the input, or returns the first misuse of
+    /// the builder.
+    pub fn build(self) -> Result<Input, String> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        self.input.validate()?;
+        Ok(self.input)
+    }
+
+    /// Validates the input and lowers it into facts, interning all
+    /// names into `tables`.
+    pub fn build_facts(self, tables: &mut InternerTables) -> Result<AllFacts, String> {
+        let input = self.build()?;
+        Ok(solve::input_facts(&input, tables))
+    }
+}
+
+impl Default for InputBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct StatementBuilder {
+    effects: Vec<Effect>,
+}
+
+impl StatementBuilder {
+    /// A mutable borrow `borrow` with the region `region`.
+    pub fn borrow(self, borrow: &str, region: &str) -> Self {
+        self.effect(Effect::Borrow {
+            borrow: borrow.to_string(),
+            region: region.to_string(),
+            kind: BorrowKind::Mut,
+            place: None,
+        })
+    }
+
+    /// A shared borrow `borrow` with the region `region`.
+    pub fn shared_borrow(self, borrow: &str, region: &str) -> Self {
+        self.effect(Effect::Borrow {
+            borrow: borrow.to_string(),
+            region: region.to_string(),
+            kind: BorrowKind::Shared,
+            place: None,
+        })
+    }
+
+    pub fn reserve(self, borrow: &str, region: &str) -> Self {
+        self.effect(Effect::Reserve {
+            borrow: borrow.to_string(),
+            region: region.to_string(),
+            place: None,
+        })
+    }
+
+    pub fn activate(self, borrow: &str) -> Self {
+        self.effect(Effect::Activate {
+            borrow: borrow.to_string(),
+        })
+    }
+
+    /// The region `region` is live on entry to the statement.
+    pub fn live(self, region: &str) -> Self {
+        self.effect(Effect::LiveOnEntry {
+            region: region.to_string(),
+        })
+    }
+
+    pub fn kill(self, borrow: &str) -> Self {
+        self.effect(Effect::Kill {
+            borrow: borrow.to_string(),
+        })
+    }
+
+    /// `pre(a: b)`
+    pub fn pre(self, a: &str, b: &str) -> Self {
+        self.effect(Effect::Outlives {
+            time: OutlivesTime::Pre,
+            a: a.to_string(),
+            b: b.to_string(),
+        })
+    }
+
+    /// `post(a: b)`
+    pub fn post(self, a: &str, b: &str) -> Self {
+        self.effect(Effect::Outlives {
+            time: OutlivesTime::Post,
+            a: a.to_string(),
+            b: b.to_string(),
+        })
+    }
+
+    pub fn drop(self, variable: &str) -> Self {
+        self.effect(Effect::Drop {
+            variable: variable.to_string(),
+        })
+    }
+
+    pub fn init(self, variable: &str) -> Self {
+        self.effect(Effect::Init {
+            variable: variable.to_string(),
+        })
+    }
+
+    /// `move(variable)`
+    pub fn moved(self, variable: &str) -> Self {
+        self.effect(Effect::Move {
+            variable: variable.to_string(),
+        })
+    }
+
+    /// `call(callee, args -> dest)`
+    pub fn call(self, callee: &str, args: &[&str], dest: Option<&str>) -> Self {
+        self.effect(Effect::Call {
+            callee: callee.to_string(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            dest: dest.map(|dest| dest.to_string()),
+        })
+    }
+
+    /// Adds any other effect, e.g. one involving a place.
+    pub fn effect(mut self, effect: Effect) -> Self {
+        self.effects.push(effect);
+        self
+    }
+}
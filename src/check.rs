@@ -0,0 +1,57 @@
+//! Checks the `assert` effects of an input against the results.
+
+use nll_souffle::ir::*;
+use nll_souffle::solve;
+use std::collections::HashSet;
+
+/// An assertion that does not hold.
+crate struct Failure {
+    /// The point of the statement containing the assertion.
+    crate point: String,
+    crate assertion: Effect,
+}
+
+/// Solves `input` and returns the assertions that do not hold, in the
+/// order in which they appear, along with the number of assertions.
+crate fn check(input: &Input) -> (Vec<Failure>, usize) {
+    let (tables, output) = solve::solve_input(input);
+    let borrow_live_at: HashSet<(&str, &str)> = output
+        .borrow_live_at
+        .iter()
+        .map(|&(b, p)| (tables.borrows.untern(b), tables.points.untern(p)))
+        .collect();
+    let errors: HashSet<(&str, &str)> = output
+        .errors
+        .iter()
+        .map(|&(b, p)| (tables.borrows.untern(b), tables.points.untern(p)))
+        .collect();
+
+    let mut failures = vec![];
+    let mut num_assertions = 0;
+    for block in &input.blocks {
+        for (index, statement) in block.statements.iter().enumerate() {
+            let point = format!("{}/{}", block.name, index);
+            for effect in &statement.effects {
+                if let Effect::Assert { assertion, negated } = effect {
+                    num_assertions += 1;
+                    let holds = match assertion {
+                        Assertion::Live { borrow } => {
+                            borrow_live_at.contains(&(borrow.as_str(), point.as_str()))
+                        }
+                        Assertion::Error { borrow } => {
+                            errors.contains(&(borrow.as_str(), point.as_str()))
+                        }
+                    };
+                    if holds == *negated {
+                        failures.push(Failure {
+                            point: point.clone(),
+                            assertion: effect.clone(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    (failures, num_assertions)
+}
@@ -0,0 +1,114 @@
+//! Runs `regions.dl` (through a local `souffle`) and our own solver on
+//! the same facts, and reports the tuples on which they disagree.
+
+use nll_souffle::intern::InternerTables;
+use nll_souffle::{solve, tab_delim};
+use std::collections::BTreeSet;
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, prelude::*};
+use std::path::Path;
+use std::process::{self, Command};
+
+crate struct Difference {
+    crate relation: &'static str,
+
+    /// Tuples that souffle derives but we do not.
+    crate missing: Vec<Vec<String>>,
+
+    /// Tuples that we derive but souffle does not.
+    crate extra: Vec<Vec<String>>,
+}
+
+/// Compares the results for the facts in `facts_dir`; an empty result
+/// means that both engines agree, and `None` that `souffle` is not
+/// installed.
+crate fn compare(facts_dir: &Path) -> Result<Option<Vec<Difference>>, Box<dyn Error>> {
+    let tables = &mut InternerTables::new();
+    let all_facts = tab_delim::load_tab_delimited_facts(tables, facts_dir)?;
+    let output = solve::solve(&all_facts);
+
+    let souffle_dir = env::temp_dir().join(format!("nll-compare-{}", process::id()));
+    let result: Result<Option<Vec<Difference>>, Box<dyn Error>> = do catch {
+        if run_souffle(facts_dir, &souffle_dir)? {
+            let mut differences = vec![];
+            for (relation, tuples) in output.relations(tables) {
+                let ours: BTreeSet<Vec<String>> =
+                    tuples.iter().map(|tuple| normalize(tuple)).collect();
+                let theirs = load_souffle_output(&souffle_dir.join(format!("{}.csv", relation)))?;
+                if ours != theirs {
+                    differences.push(Difference {
+                        relation,
+                        missing: theirs.difference(&ours).cloned().collect(),
+                        extra: ours.difference(&theirs).cloned().collect(),
+                    });
+                }
+            }
+            Ok(Some(differences))
+        } else {
+            Ok(None)
+        }
+    };
+    let _ = fs::remove_dir_all(&souffle_dir);
+    result
+}
+
+/// Runs souffle, writing its results to `output_dir`; false if it is
+/// not installed.
+fn run_souffle(facts_dir: &Path, output_dir: &Path) -> Result<bool, Box<dyn Error>> {
+    fs::create_dir_all(output_dir)?;
+    let regions_dl = Path::new(env!("CARGO_MANIFEST_DIR")).join("regions.dl");
+    let status = Command::new("souffle")
+        .arg(&regions_dl)
+        .arg("-F")
+        .arg(facts_dir)
+        .arg("-D")
+        .arg(output_dir)
+        .status();
+    let status = match status {
+        Ok(status) => status,
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(false),
+        Err(err) => return Err(format!("running `souffle`: {}", err).into()),
+    };
+    if !status.success() {
+        return Err(format!("`souffle` failed ({})", status).into());
+    }
+    Ok(true)
+}
+
+/// Souffle writes one tab-delimited `.csv` file per output relation.
+fn load_souffle_output(path: &Path) -> Result<BTreeSet<Vec<String>>, Box<dyn Error>> {
+    let mut text = String::new();
+    File::open(path)?.read_to_string(&mut text)?;
+    Ok(text
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let tuple: Vec<&str> = line.split('\t').collect();
+            normalize(&tuple)
+        })
+        .collect())
+}
+
+/// Facts files (and hence souffle's output) quote every name, whereas
+/// names that we interned from an input file are not quoted.
+fn normalize(tuple: &[impl AsRef<str>]) -> Vec<String> {
+    tuple
+        .iter()
+        .map(|name| name.as_ref().trim_matches('"').to_string())
+        .collect()
+}
+
+impl Difference {
+    crate fn dump(&self) {
+        println!("vvv {} vvv", self.relation);
+        for tuple in &self.missing {
+            println!("missing: {}", tuple.join(" "));
+        }
+        for tuple in &self.extra {
+            println!("extra: {}", tuple.join(" "));
+        }
+        println!("^^^ {} ^^^", self.relation);
+    }
+}
@@ -0,0 +1,49 @@
+//! The control-flow graph of an input, in graphviz's `dot` format:
+//! one node per block, listing its statements and terminator, with
+//! dashed edges for unwinding.
+
+use nll_souffle::ir::*;
+use std::fmt::Write;
+
+crate fn dot(name: &str, input: &Input) -> String {
+    let mut dot = String::new();
+    writeln!(dot, "digraph {} {{", quoted(name)).unwrap();
+    writeln!(dot, "  node [shape = box, fontname = monospace];").unwrap();
+    for block in &input.blocks {
+        // `\l` ends a left-justified line
+        let mut label = format!("{}\\l", escaped(&block.name));
+        for (index, statement) in block.statements.iter().enumerate() {
+            let effects: Vec<String> = statement.effects.iter().map(|e| e.to_string()).collect();
+            let line = format!("{}/{}: {}", block.name, index, effects.join(" "));
+            label.push_str(&format!("{}\\l", escaped(line.trim_right())));
+        }
+        if block.terminator != Terminator::default() {
+            let line = format!("{}/{}: {}", block.name, block.statements.len(), block.terminator);
+            label.push_str(&format!("{}\\l", escaped(&line)));
+        }
+        writeln!(dot, "  {} [label = \"{}\"];", quoted(&block.name), label).unwrap();
+    }
+    for block in &input.blocks {
+        for target in block.terminator.successors() {
+            writeln!(dot, "  {} -> {};", quoted(&block.name), quoted(target)).unwrap();
+        }
+        if let Some(target) = block.terminator.unwind() {
+            writeln!(
+                dot,
+                "  {} -> {} [style = dashed];",
+                quoted(&block.name),
+                quoted(target)
+            ).unwrap();
+        }
+    }
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+fn quoted(text: &str) -> String {
+    format!("\"{}\"", escaped(text))
+}
+
+fn escaped(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,22 @@
+//! Errors shared between the ways of reading inputs.
+
+use std::error::Error;
+use std::fmt;
+
+/// An input that cannot be parsed, or is otherwise malformed (as
+/// opposed to one that cannot be read at all, which is an
+/// `io::Error`).
+#[derive(Debug)]
+pub struct InvalidInput(pub String);
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for InvalidInput {
+    fn description(&self) -> &str {
+        &self.0
+    }
+}
@@ -15,29 +15,49 @@ macro_rules! from_usize {
 }
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Abomonation, Debug, Hash)]
-pub(crate) struct Region {
+pub struct Region {
     index: u32,
 }
 from_usize!(Region);
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Abomonation, Debug, Hash)]
-pub(crate) struct Borrow {
+pub struct Borrow {
     index: u32,
 }
 from_usize!(Borrow);
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Abomonation, Debug, Hash)]
-pub(crate) struct Point {
+pub struct Point {
     index: u32,
 }
 from_usize!(Point);
 
-crate struct AllFacts {
-    crate borrow_region: Vec<(Region, Borrow, Point)>,
-    crate next_statement: Vec<(Point, Point)>,
-    crate goto: Vec<(Point, Point)>,
-    crate region_live_on_entry: Vec<(Region, Point)>,
-    crate killed: Vec<(Borrow, Point)>,
-    crate outlives: Vec<(Point, Region, Region, Point)>,
+#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Copy, Abomonation, Debug, Hash)]
+pub struct Variable {
+    index: u32,
+}
+from_usize!(Variable);
+
+#[derive(Clone)]
+pub struct AllFacts {
+    pub borrow_region: Vec<(Region, Borrow, Point)>,
+    pub next_statement: Vec<(Point, Point)>,
+    pub goto: Vec<(Point, Point)>,
+
+    /// Edges from a terminator to a cleanup block, taken only when
+    /// unwinding; they are not part of `goto`.
+    pub unwind_edge: Vec<(Point, Point)>,
+    pub region_live_on_entry: Vec<(Region, Point)>,
+    pub killed: Vec<(Borrow, Point)>,
+    pub outlives: Vec<(Point, Region, Region, Point)>,
+    pub var_drop: Vec<(Variable, Point)>,
+    pub var_drop_region: Vec<(Variable, Region)>,
+    pub var_init: Vec<(Variable, Point)>,
+    pub var_moved: Vec<(Variable, Point)>,
+    pub borrow_reserved: Vec<(Borrow, Point)>,
+    pub borrow_activated: Vec<(Borrow, Point)>,
+    pub mut_borrow: Vec<Borrow>,
+    pub borrowed_path_read: Vec<(Borrow, Point)>,
+    pub borrowed_path_written: Vec<(Borrow, Point)>,
 }
 
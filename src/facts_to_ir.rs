@@ -0,0 +1,357 @@
+//! Reconstructs an input program from a set of facts -- the reverse of
+//! lowering. This relies on points being named `block/index`, as they
+//! are when the facts were produced from an input file; the result is
+//! meant to be minimized by hand.
+//!
+//! The result lowers to the same facts, although not always through
+//! the same effects: kills due to assignments become plain `kill`s,
+//! and a borrow whose path is read or written somewhere is given a
+//! path of its own (a local named after the borrow), which the reads
+//! and writes then access.
+//!
+//! Names that are not identifiers in the input language, such as the
+//! `foo::B0` of a function `foo` or the `f::'a@B0/1` of a call, are
+//! renamed (see `Renaming`), and the renaming is listed in a comment
+//! at the top of the result.
+
+use nll_souffle::facts::*;
+use nll_souffle::intern::InternerTables;
+use nll_souffle::ir::*;
+use nll_souffle::{facts, ir};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+#[derive(Default)]
+struct BlockData {
+    num_statements: usize,
+    goto: Vec<String>,
+    effects: BTreeMap<usize, Vec<Effect>>,
+}
+
+crate fn facts_to_ir(tables: &InternerTables, all_facts: &AllFacts) -> Result<Input, String> {
+    let mut blocks: BTreeMap<String, BlockData> = BTreeMap::new();
+
+    let mut names: BTreeSet<String> = BTreeSet::new();
+    for name in tables
+        .regions
+        .strings()
+        .iter()
+        .chain(tables.borrows.strings())
+        .chain(tables.variables.strings())
+    {
+        names.insert(unquote(name));
+    }
+    for point in tables.points.strings() {
+        if let Ok((block, _)) = parse_point(point) {
+            names.insert(block);
+        }
+    }
+    let renaming = Renaming::new(names);
+
+    let point = |p: Point| -> Result<(String, usize), String> {
+        let (block, index) = parse_point(tables.points.untern(p))?;
+        Ok((renaming.name(&block), index))
+    };
+    let region = |r: Region| renaming.name(&unquote(tables.regions.untern(r)));
+    let borrow = |b: Borrow| renaming.name(&unquote(tables.borrows.untern(b)));
+    let variable = |v: facts::Variable| renaming.name(&unquote(tables.variables.untern(v)));
+
+    for &(p, q) in &all_facts.next_statement {
+        let (block_p, index_p) = point(p)?;
+        let (block_q, index_q) = point(q)?;
+        if block_p != block_q || index_p + 1 != index_q {
+            return Err(format!(
+                "`nextStatement` edge from `{}/{}` to `{}/{}` is not within a block",
+                block_p, index_p, block_q, index_q,
+            ));
+        }
+        let data = blocks.entry(block_p).or_insert_with(BlockData::default);
+        data.num_statements = data.num_statements.max(index_q);
+    }
+
+    for &(p, q) in &all_facts.goto {
+        let (block_p, _) = point(p)?;
+        let (block_q, index_q) = point(q)?;
+        if index_q != 0 {
+            return Err(format!("`goto` edge into the middle of block `{}`", block_q));
+        }
+        blocks.entry(block_q.clone()).or_insert_with(BlockData::default);
+        let data = blocks.entry(block_p).or_insert_with(BlockData::default);
+        if !data.goto.contains(&block_q) {
+            data.goto.push(block_q);
+        }
+    }
+
+    // only `goto` terminators are reconstructed
+    if !all_facts.unwind_edge.is_empty() {
+        return Err("unwind edges cannot be reconstructed".to_string());
+    }
+
+    // the regions of each variable that its destructor may access;
+    // `may_dangle` regions leave no trace in the facts
+    let mut variables: BTreeMap<String, Vec<VariableRegion>> = BTreeMap::new();
+    for &(v, r) in &all_facts.var_drop_region {
+        variables
+            .entry(variable(v))
+            .or_insert_with(Vec::new)
+            .push(VariableRegion {
+                region: region(r),
+                may_dangle: false,
+            });
+    }
+
+    // (scoped so that `add_effect` releases its borrow of `blocks`)
+    {
+        let mut add_effect = |(block, index): (String, usize), effect: Effect| {
+            blocks
+                .entry(block)
+                .or_insert_with(BlockData::default)
+                .effects
+                .entry(index)
+                .or_insert_with(Vec::new)
+                .push(effect);
+        };
+
+        let mut_borrows: HashSet<Borrow> = all_facts.mut_borrow.iter().cloned().collect();
+        let mut reserved: BTreeSet<(Borrow, Point)> =
+            all_facts.borrow_reserved.iter().cloned().collect();
+        let path_borrows: BTreeSet<Borrow> = all_facts
+            .borrowed_path_read
+            .iter()
+            .chain(&all_facts.borrowed_path_written)
+            .map(|&(b, _p)| b)
+            .collect();
+        let mut placed_borrows: HashSet<Borrow> = HashSet::new();
+        for &(r, b, p) in &all_facts.borrow_region {
+            let place = if path_borrows.contains(&b) {
+                placed_borrows.insert(b);
+                Some(Place::Local(borrow(b)))
+            } else {
+                None
+            };
+            let effect = if reserved.remove(&(b, p)) {
+                Effect::Reserve {
+                    borrow: borrow(b),
+                    region: region(r),
+                    place,
+                }
+            } else {
+                let kind = if mut_borrows.contains(&b) {
+                    BorrowKind::Mut
+                } else {
+                    BorrowKind::Shared
+                };
+                Effect::Borrow {
+                    borrow: borrow(b),
+                    region: region(r),
+                    kind,
+                    place,
+                }
+            };
+            add_effect(point(p)?, effect);
+        }
+        if let Some(&(b, p)) = reserved.iter().next() {
+            return Err(format!(
+                "borrow `{}` is reserved at `{}`, but not created there",
+                borrow(b),
+                tables.points.untern(p),
+            ));
+        }
+        if let Some(&b) = path_borrows.iter().find(|b| !placed_borrows.contains(b)) {
+            return Err(format!(
+                "the path of borrow `{}` is accessed, but the borrow is never created",
+                borrow(b),
+            ));
+        }
+
+        for &(b, p) in &all_facts.borrow_activated {
+            add_effect(point(p)?, Effect::Activate { borrow: borrow(b) });
+        }
+
+        for &(b, p) in &all_facts.borrowed_path_read {
+            let place = Place::Local(borrow(b));
+            add_effect(point(p)?, Effect::Read { place });
+        }
+
+        for &(b, p) in &all_facts.borrowed_path_written {
+            let place = Place::Local(borrow(b));
+            add_effect(point(p)?, Effect::Write { place });
+        }
+
+        for &(r, p) in &all_facts.region_live_on_entry {
+            add_effect(point(p)?, Effect::LiveOnEntry { region: region(r) });
+        }
+
+        for &(b, p) in &all_facts.killed {
+            add_effect(point(p)?, Effect::Kill { borrow: borrow(b) });
+        }
+
+        for &(p, a, b, q) in &all_facts.outlives {
+            let (block_p, index_p) = point(p)?;
+            let (block_q, index_q) = point(q)?;
+            let time = if (&block_p, index_p) == (&block_q, index_q) {
+                OutlivesTime::Pre
+            } else if block_p == block_q && index_p + 1 == index_q {
+                OutlivesTime::Post
+            } else {
+                return Err(format!(
+                    "`outlives` from `{}/{}` to `{}/{}` is neither pre nor post",
+                    block_p, index_p, block_q, index_q,
+                ));
+            };
+            add_effect(
+                (block_p, index_p),
+                Effect::Outlives {
+                    time,
+                    a: region(a),
+                    b: region(b),
+                },
+            );
+        }
+
+        let mut add_variable_effect =
+            |v: facts::Variable, p: Point, effect: Effect| -> Result<(), String> {
+                variables.entry(variable(v)).or_insert_with(Vec::new);
+                add_effect(point(p)?, effect);
+                Ok(())
+            };
+        for &(v, p) in &all_facts.var_drop {
+            add_variable_effect(v, p, Effect::Drop { variable: variable(v) })?;
+        }
+        for &(v, p) in &all_facts.var_init {
+            add_variable_effect(v, p, Effect::Init { variable: variable(v) })?;
+        }
+        for &(v, p) in &all_facts.var_moved {
+            add_variable_effect(v, p, Effect::Move { variable: variable(v) })?;
+        }
+    }
+
+    let mut result = Input {
+        comments: renaming.comments(),
+        signatures: vec![],
+        variables: variables
+            .into_iter()
+            .map(|(name, regions)| ir::Variable { name, regions })
+            .collect(),
+        blocks: vec![],
+    };
+    for (name, data) in blocks {
+        let mut statements: Vec<Statement> = (0..data.num_statements)
+            .map(|_| Statement {
+                comments: vec![],
+                effects: vec![],
+            })
+            .collect();
+        for (index, effects) in data.effects {
+            match statements.get_mut(index) {
+                Some(statement) => statement.effects.extend(effects),
+                None => {
+                    return Err(format!(
+                        "effects at `{}/{}`, which is not a statement",
+                        name, index
+                    ))
+                }
+            }
+        }
+        result.blocks.push(Block {
+            name,
+            statements,
+            trailing_comments: vec![],
+            terminator: Terminator::Goto { targets: data.goto },
+        });
+    }
+
+    Ok(result)
+}
+
+/// Splits a point like `"B0/3"` into its block and statement index.
+fn parse_point(point: &str) -> Result<(String, usize), String> {
+    let point = unquote(point);
+    let (block, index) = match point.rfind('/') {
+        Some(slash) => (&point[..slash], &point[slash + 1..]),
+        None => return Err(format!("point `{}` is not of the form `block/index`", point)),
+    };
+    match index.parse() {
+        Ok(index) => Ok((block.to_string(), index)),
+        Err(_) => Err(format!("point `{}` is not of the form `block/index`", point)),
+    }
+}
+
+/// The words of the input language, which cannot be used as names.
+const KEYWORDS: &[&str] = &[
+    "activate", "as", "assert", "assign", "block", "borrow", "call", "drop", "error", "fn",
+    "goto", "init", "kill", "live", "liveOnEntry", "may_dangle", "move", "mut", "post", "pre",
+    "read", "reserve", "return", "shared", "sig", "statement", "switch", "unreachable",
+    "unwind", "var", "write",
+];
+
+/// Maps names that are not identifiers to identifiers, replacing each
+/// character that cannot appear in one by `_` (and adding a suffix if
+/// the result is taken). Names are renamed in sorted order, after all
+/// valid names have been reserved, so the renaming only depends on the
+/// set of names.
+struct Renaming {
+    renamed: BTreeMap<String, String>,
+}
+
+impl Renaming {
+    fn new(names: BTreeSet<String>) -> Self {
+        let mut taken: HashSet<String> = names
+            .iter()
+            .filter(|name| is_identifier(name))
+            .cloned()
+            .collect();
+        let mut renamed = BTreeMap::new();
+        for name in names.iter().filter(|name| !is_identifier(name)) {
+            let mut base: String = name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            if !base.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+                base.insert(0, '_');
+            }
+            if KEYWORDS.contains(&base.as_str()) {
+                base.push('_');
+            }
+            let mut candidate = base.clone();
+            let mut suffix = 1;
+            while taken.contains(&candidate) {
+                suffix += 1;
+                candidate = format!("{}_{}", base, suffix);
+            }
+            taken.insert(candidate.clone());
+            renamed.insert(name.clone(), candidate);
+        }
+        Renaming { renamed }
+    }
+
+    fn name(&self, name: &str) -> String {
+        match self.renamed.get(name) {
+            Some(renamed) => renamed.clone(),
+            None => name.to_string(),
+        }
+    }
+
+    /// Lists the renamed names, as comments.
+    fn comments(&self) -> Vec<String> {
+        if self.renamed.is_empty() {
+            return vec![];
+        }
+        let mut comments = vec!["// renamed, as they are not identifiers:".to_string()];
+        for (name, renamed) in &self.renamed {
+            comments.push(format!("//   `{}` to `{}`", name, renamed));
+        }
+        comments
+    }
+}
+
+/// True if `name` can be used as a name in the input language.
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+/// Facts files quote every name; the input syntax does not.
+fn unquote(name: &str) -> String {
+    name.trim_matches('"').to_string()
+}
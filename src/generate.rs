@@ -0,0 +1,145 @@
+//! Generates random (but well-formed) inputs, so that we can test the
+//! solver on many more programs than we would ever write by hand. The
+//! same seed always yields the same input.
+
+use nll_souffle::ir::*;
+
+/// Controls the size of the generated inputs.
+#[derive(Copy, Clone, Debug)]
+crate struct GenerateOptions {
+    crate blocks: usize,
+
+    /// The maximum number of statements in a block.
+    crate statements: usize,
+
+    /// The maximum number of effects in a statement.
+    crate effects: usize,
+
+    /// The number of distinct borrows; each borrow `Ln` has a region
+    /// of the same name.
+    crate borrows: usize,
+
+    /// The number of regions `Rn`, besides those of the borrows.
+    crate regions: usize,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            blocks: 4,
+            statements: 4,
+            effects: 3,
+            borrows: 3,
+            regions: 3,
+        }
+    }
+}
+
+crate fn generate(seed: u64, options: &GenerateOptions) -> Input {
+    assert!(options.blocks > 0 && options.borrows > 0);
+    let rng = &mut Rng::new(seed);
+
+    let blocks = (0..options.blocks)
+        .map(|index| {
+            let statements = (0..rng.below(options.statements + 1))
+                .map(|_| Statement {
+                    comments: vec![],
+                    effects: (0..rng.below(options.effects + 1))
+                        .map(|_| random_effect(rng, options))
+                        .collect(),
+                })
+                .collect();
+
+            // Any block may jump to any other (or to itself), so we get
+            // loops as well as blocks that are never reached.
+            let mut goto = vec![];
+            for _ in 0..rng.below(3) {
+                let target = format!("B{}", rng.below(options.blocks));
+                if !goto.contains(&target) {
+                    goto.push(target);
+                }
+            }
+
+            Block {
+                name: format!("B{}", index),
+                statements,
+                trailing_comments: vec![],
+                terminator: Terminator::Goto { targets: goto },
+            }
+        })
+        .collect();
+
+    Input {
+        comments: vec![format!("// generated from seed {}", seed)],
+        signatures: vec![],
+        variables: vec![],
+        blocks,
+    }
+}
+
+fn random_effect(rng: &mut Rng, options: &GenerateOptions) -> Effect {
+    match rng.below(5) {
+        0 => {
+            let borrow = format!("L{}", rng.below(options.borrows));
+            Effect::Borrow {
+                borrow: borrow.clone(),
+                region: borrow,
+                kind: BorrowKind::Mut,
+                place: None,
+            }
+        }
+        1 => Effect::LiveOnEntry {
+            region: random_region(rng, options),
+        },
+        2 => Effect::Kill {
+            borrow: format!("L{}", rng.below(options.borrows)),
+        },
+        _ => Effect::Outlives {
+            time: if rng.below(2) == 0 {
+                OutlivesTime::Pre
+            } else {
+                OutlivesTime::Post
+            },
+            a: random_region(rng, options),
+            b: random_region(rng, options),
+        },
+    }
+}
+
+fn random_region(rng: &mut Rng, options: &GenerateOptions) -> String {
+    let index = rng.below(options.borrows + options.regions);
+    if index < options.borrows {
+        format!("L{}", index)
+    } else {
+        format!("R{}", index - options.borrows)
+    }
+}
+
+/// A xorshift generator: not much in the way of randomness, but
+/// plenty for this, and it saves us a dependency.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // the state must never be zero
+        Rng {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+
+    fn next(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// Returns a number in `0..n`.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
@@ -1,7 +1,7 @@
 use facts::*;
 use std::collections::HashMap;
 
-crate struct Interner<TargetType: From<usize> + Copy> {
+pub struct Interner<TargetType: From<usize> + Copy> {
     strings: HashMap<String, TargetType>,
     rev_strings: Vec<String>,
 }
@@ -17,12 +17,17 @@ where
         }
     }
 
-    crate fn untern(&self, data: TargetType) -> &str {
+    pub fn untern(&self, data: TargetType) -> &str {
         let data: usize = data.into();
         &self.rev_strings[data]
     }
 
-    crate fn intern(&mut self, data: &str) -> TargetType {
+    /// Every string interned so far, in the order they were interned.
+    pub fn strings(&self) -> &[String] {
+        &self.rev_strings
+    }
+
+    pub fn intern(&mut self, data: &str) -> TargetType {
         if let Some(&interned) = self.strings.get(data) {
             return interned;
         }
@@ -33,18 +38,20 @@ where
     }
 }
 
-crate struct InternerTables {
-    crate regions: Interner<Region>,
-    crate borrows: Interner<Borrow>,
-    crate points: Interner<Point>,
+pub struct InternerTables {
+    pub regions: Interner<Region>,
+    pub borrows: Interner<Borrow>,
+    pub points: Interner<Point>,
+    pub variables: Interner<Variable>,
 }
 
 impl InternerTables {
-    crate fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             regions: Interner::new(),
             borrows: Interner::new(),
             points: Interner::new(),
+            variables: Interner::new(),
         }
     }
 }
@@ -66,6 +73,7 @@ macro_rules! intern_impl {
 intern_impl!(Region, regions);
 intern_impl!(Borrow, borrows);
 intern_impl!(Point, points);
+intern_impl!(Variable, variables);
 
 impl<A, FromA, B, FromB> InternTo<(A, B)> for (FromA, FromB)
 where
@@ -1,35 +1,321 @@
+/// A whole input file: either a single function, given directly by its
+/// variables and blocks, or any number of `fn name { ... }`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Program {
+    /// Comments at the start of the file, before the first `fn`.
+    #[serde(default)]
+    pub comments: Vec<String>,
+
+    /// Signatures declared before the first `fn`, which are available
+    /// to all functions.
+    #[serde(default)]
+    pub signatures: Vec<Signature>,
+    pub functions: Vec<Function>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Function {
+    /// `None` for the single function of a file without `fn`.
+    pub name: Option<String>,
+    pub input: Input,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Input {
+    /// Comments at the start of the file (including the `//`).
+    #[serde(default)]
+    pub comments: Vec<String>,
+    #[serde(default)]
+    pub signatures: Vec<Signature>,
+    #[serde(default)]
+    pub variables: Vec<Variable>,
     pub blocks: Vec<Block>,
 }
 
+/// The signature of a function that can be called with `Effect::Call`,
+/// e.g. `sig maybe_next<'a>(&'a mut T) -> Option<&'a mut T>`. Only the
+/// lifetimes in the types matter.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Signature {
+    pub name: String,
+
+    /// The lifetime parameters (without the leading `'`).
+    pub lifetimes: Vec<String>,
+    pub inputs: Vec<Type>,
+    pub output: Option<Type>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Type {
+    /// `&'a T` or `&'a mut T`.
+    Ref { lifetime: String, mutable: bool, referent: Box<Type> },
+
+    /// `T` or `Foo<'a, T>`.
+    Path { name: String, args: Vec<GenericArg> },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GenericArg {
+    Lifetime(String),
+    Type(Type),
+}
+
+/// A local variable, declared as `var x { R1 may_dangle(R2) }`. The
+/// regions are those appearing in the variable's type; they only
+/// matter when the variable is dropped (see `Effect::Drop`).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub name: String,
+    pub regions: Vec<VariableRegion>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VariableRegion {
+    pub region: String,
+
+    /// True if the destructor of the variable promises not to access
+    /// data with this region (`#[may_dangle]`); dropping the variable
+    /// therefore does not require the region to be live.
+    #[serde(default)]
+    pub may_dangle: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Block {
     pub name: String,
+    #[serde(default)]
     pub statements: Vec<Statement>,
-    pub goto: Vec<String>,
+
+    /// Comments after the last statement, before the terminator.
+    #[serde(default)]
+    pub trailing_comments: Vec<String>,
+    #[serde(default)]
+    pub terminator: Terminator,
+}
+
+/// How control leaves a block. The terminator executes at the point
+/// after the last statement (`B/n` for a block `B` with `n`
+/// statements), and its edges lead to the first point of each target
+/// block. Edges taken only when unwinding from a panic (`unwind`) are
+/// kept apart from the normal ones.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Terminator {
+    /// `goto { B1 B2 }`; a block without a terminator is `goto { }`.
+    Goto { targets: Vec<String> },
+
+    /// `switch(x) { B1 B2 }`: reads `place` and jumps to one of the
+    /// targets.
+    Switch { place: Place, targets: Vec<String> },
+
+    /// `call(f, args -> dest) -> B1 unwind B2`: like `Effect::Call`,
+    /// except that `dest` is assigned on the edge to `target`.
+    Call {
+        callee: String,
+        args: Vec<String>,
+        dest: Option<String>,
+        target: String,
+        unwind: Option<String>,
+    },
+
+    /// `drop(x) -> B1 unwind B2`: drops `variable`, like `Effect::Drop`.
+    Drop { variable: String, target: String, unwind: Option<String> },
+
+    /// `return`
+    Return,
+
+    /// `unreachable`
+    Unreachable,
 }
 
+impl Terminator {
+    /// The targets of the normal (non-unwind) edges.
+    pub fn successors(&self) -> Vec<&str> {
+        match self {
+            Terminator::Goto { targets } | Terminator::Switch { targets, .. } => {
+                targets.iter().map(|target| target.as_str()).collect()
+            }
+            Terminator::Call { target, .. } | Terminator::Drop { target, .. } => vec![target.as_str()],
+            Terminator::Return | Terminator::Unreachable => vec![],
+        }
+    }
+
+    /// The target of the unwind edge, if any.
+    pub fn unwind(&self) -> Option<&str> {
+        match self {
+            Terminator::Call { unwind, .. } | Terminator::Drop { unwind, .. } => {
+                unwind.as_ref().map(|unwind| unwind.as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// All targets, including that of the unwind edge.
+    pub fn targets_mut(&mut self) -> Vec<&mut String> {
+        match self {
+            Terminator::Goto { targets } | Terminator::Switch { targets, .. } => {
+                targets.iter_mut().collect()
+            }
+            Terminator::Call { target, unwind, .. } | Terminator::Drop { target, unwind, .. } => {
+                let mut result = vec![target];
+                result.extend(unwind);
+                result
+            }
+            Terminator::Return | Terminator::Unreachable => vec![],
+        }
+    }
+}
+
+impl Default for Terminator {
+    fn default() -> Self {
+        Terminator::Goto { targets: vec![] }
+    }
+}
+
+impl Program {
+    /// See `Input::remove_unwind_edges`.
+    pub fn remove_unwind_edges(&mut self) {
+        for function in &mut self.functions {
+            function.input.remove_unwind_edges();
+        }
+    }
+}
+
+impl Input {
+    /// Removes all unwind edges, so that cleanup paths are ignored; the
+    /// cleanup blocks themselves are kept, but are no longer reachable
+    /// through unwinding.
+    pub fn remove_unwind_edges(&mut self) {
+        for block in &mut self.blocks {
+            match &mut block.terminator {
+                Terminator::Call { unwind, .. } | Terminator::Drop { unwind, .. } => *unwind = None,
+                _ => {}
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct Statement {
+    /// Comments preceding the statement.
+    #[serde(default)]
+    pub comments: Vec<String>,
     pub effects: Vec<Effect>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum Effect {
     /// A borrow `borrow` occured in this statement; the resulting
     /// reference had the region `region` (these are often given the
     /// same name). This is typically accompanied by a `post` outlives
     /// requirement for the variable where the reference is stored.
-    Borrow { borrow: String, region: String },
+    ///
+    /// If `place` is given, the borrowed path is known, and `killed`
+    /// facts and conflicts with accesses are derived from it.
+    Borrow { borrow: String, region: String, kind: BorrowKind, place: Option<Place> },
+
+    /// A two-phase (and hence mutable) borrow `borrow` is reserved in
+    /// this statement, with the region `region`. Until it is activated
+    /// (see `Activate`), the borrowed path may still be read.
+    Reserve { borrow: String, region: String, place: Option<Place> },
+
+    /// The two-phase borrow `borrow` is activated in this statement,
+    /// i.e., the reference is first used for mutation.
+    Activate { borrow: String },
 
     /// Indicates that a region is live on entry to this statement.
     LiveOnEntry { region: String },
     Kill { borrow: String },
 
+    /// This statement overwrites `place`. This kills all borrows of
+    /// paths that have `place` as a prefix, and conflicts with live
+    /// borrows of overlapping paths (except those reached through a
+    /// dereference of `place`, which are merely killed).
+    Assign { place: Place },
+
+    /// This statement reads `place`; this is an error if there is an
+    /// active mutable borrow of an overlapping path.
+    Read { place: Place },
+
+    /// This statement writes `place` (without overwriting it, e.g.
+    /// through `*place = ...`); this is an error if there is a live
+    /// borrow of an overlapping path.
+    Write { place: Place },
+
     /// Creates an outlives requirement indicating data froms from `a`
     /// into `b`; this is positioned at the start of the statement,
     /// and hence it indicates that anything that region `a` points
     /// to, region `b` may now point to. Used for assignments.
     Outlives { time: OutlivesTime, a: String, b: String },
+
+    /// The variable `variable` is dropped in this statement. Unlike a
+    /// regular use, this only keeps alive the regions that the
+    /// destructor may access (i.e., those not marked `may_dangle`).
+    Drop { variable: String },
+
+    /// The variable `variable` is (re)initialized by this statement.
+    Init { variable: String },
+
+    /// The variable `variable` is moved out of by this statement; it
+    /// is uninitialized afterwards, so later drops of it do not
+    /// require any regions to be live.
+    Move { variable: String },
+
+    /// An expectation about the results, which is ignored when
+    /// lowering and checked by `check`: `assert live(B)` holds if
+    /// `B` is live on entry to this statement, `assert !live(B)` if it
+    /// is not.
+    Assert { assertion: Assertion, negated: bool },
+
+    /// A call `dest = callee(args)` of a function declared with `sig`.
+    /// Each call instantiates the lifetime parameters of the signature
+    /// with fresh regions; the regions of the arguments flow into those
+    /// of the parameter types on entry, and those of the return type
+    /// flow into the regions of `dest` on exit. The regions of a
+    /// variable are the ones declared with `var`, or, if there is no
+    /// such declaration, the region of the same name.
+    Call { callee: String, args: Vec<String>, dest: Option<String> },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Assertion {
+    /// `live(B)`: `borrowLiveAt` contains `B` at this statement.
+    Live { borrow: String },
+
+    /// `error(B)`: `errors` contains `B` at this statement.
+    Error { borrow: String },
+}
+
+/// A place expression: `x`, `*x`, `x.f`, `(*x).f` and so forth.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Place {
+    Local(String),
+    Deref(Box<Place>),
+    Field(Box<Place>, String),
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BorrowKind {
+    Shared,
+    Mut,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub enum OutlivesTime {
     Pre,
     Post,
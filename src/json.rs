@@ -0,0 +1,117 @@
+//! Programs in JSON, for inputs produced by other tools (such as a MIR
+//! extractor) rather than written by hand. The schema mirrors `ir`
+//! field for field, with the names of fields and enum variants in
+//! camelCase:
+//!
+//! ```json
+//! {
+//!   "functions": [{
+//!     "name": null,
+//!     "input": {
+//!       "blocks": [{
+//!         "name": "B0",
+//!         "statements": [{
+//!           "effects": [
+//!             { "borrow": { "borrow": "B_x", "region": "B_x", "kind": "mut" } },
+//!             { "outlives": { "time": "post", "a": "B_x", "b": "x" } }
+//!           ]
+//!         }],
+//!         "terminator": { "goto": { "targets": ["B1"] } }
+//!       }]
+//!     }
+//!   }]
+//! }
+//! ```
+//!
+//! Comments, signatures, variables, statements, `mayDangle` and
+//! terminators may be left out, as may any optional field. A `.json`
+//! file holds either a `Program` or a single `Input`; a `.jsonl` file
+//! holds one `Function` per line. Every program is validated (see
+//! `Program::validate`), as are the programs in the input language
+//! that are read with `read_program`.
+
+use error::InvalidInput;
+use ir::*;
+use parser::ProgramParser;
+use serde_json;
+use std::error::Error;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::Path;
+
+/// Parses a `.json` file. If it is neither a `Program` nor an `Input`,
+/// the error is the one for a `Program` if it has `functions`, and the
+/// one for an `Input` otherwise.
+pub fn parse_program(text: &str) -> Result<Program, String> {
+    let program = match serde_json::from_str::<Program>(text) {
+        Ok(program) => program,
+        Err(program_err) => match serde_json::from_str::<Input>(text) {
+            Ok(input) => Program {
+                comments: vec![],
+                signatures: vec![],
+                functions: vec![Function { name: None, input }],
+            },
+            Err(input_err) => {
+                let has_functions = serde_json::from_str::<serde_json::Value>(text)
+                    .ok()
+                    .map_or(false, |value| value.get("functions").is_some());
+                let err = if has_functions {
+                    program_err
+                } else {
+                    input_err
+                };
+                return Err(err.to_string());
+            }
+        },
+    };
+    program.validate()?;
+    Ok(program)
+}
+
+/// Parses a `.jsonl` file; blank lines are ignored.
+pub fn parse_functions(text: &str) -> Result<Program, String> {
+    let mut functions = vec![];
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let function: Function = serde_json::from_str(line)
+            .map_err(|err| format!("line {}: {}", index + 1, err))?;
+        functions.push(function);
+    }
+    let program = Program {
+        comments: vec![],
+        signatures: vec![],
+        functions,
+    };
+    program.validate()?;
+    Ok(program)
+}
+
+/// True if `path` is read as JSON (or JSON Lines) by `read_program`.
+pub fn is_json(path: &Path) -> bool {
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") | Some("jsonl") => true,
+        _ => false,
+    }
+}
+
+/// Reads and validates the program in `path`, as JSON if its extension
+/// is `.json` or `.jsonl`, and in the input language otherwise. Inputs
+/// that do not parse or are not valid are `InvalidInput`.
+pub fn read_program(path: &Path) -> Result<Program, Box<dyn Error>> {
+    let mut text = String::new();
+    File::open(path)?.read_to_string(&mut text)?;
+    let program = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("json") => parse_program(&text),
+        Some("jsonl") => parse_functions(&text),
+        _ => ProgramParser::new()
+            .parse(&text)
+            .map_err(|err| err.to_string())
+            .and_then(|program| {
+                program.validate()?;
+                Ok(program)
+            }),
+    };
+    program.map_err(|err| InvalidInput(err).into())
+}
@@ -0,0 +1,44 @@
+//! Location-sensitive region inference for NLL, computed from a set of
+//! input facts. The input facts can be produced from the small input
+//! language in `ir` (see `parser`) or its JSON form (see `json`), or
+//! loaded from `.facts` files (see `tab_delim`).
+//!
+//! The entry point is `solve`; see the `examples` directory for how
+//! to use it.
+
+#![feature(crate_in_paths)]
+#![feature(crate_visibility_modifier)]
+#![feature(dyn_trait)]
+#![feature(in_band_lifetimes)]
+#![feature(match_default_bindings)]
+
+#[macro_use]
+extern crate abomonation_derive;
+extern crate abomonation;
+extern crate differential_dataflow;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
+extern crate serde_json;
+extern crate timely;
+
+pub mod builder;
+pub mod error;
+pub mod facts;
+pub mod intern;
+pub mod ir;
+pub mod json;
+pub mod lint;
+mod lower;
+pub mod parser;
+mod pretty;
+mod program;
+pub mod solve;
+pub mod tab_delim;
+mod validate;
+
+pub use builder::InputBuilder;
+pub use error::InvalidInput;
+pub use facts::AllFacts;
+pub use intern::InternerTables;
+pub use solve::{solve, Output, SolveOptions};
@@ -0,0 +1,167 @@
+//! Lints for hand-written inputs. These look at the input alone,
+//! without solving it, and flag liveness that is likely a mistake:
+//! a `liveOnEntry` missing in the middle of a run of statements where
+//! a region is live, and regions that are live but play no part in
+//! any borrow, outlives relation or destructor.
+
+use ir::*;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// `region` is live on entry to the statements `before` and
+    /// `after` of the same block, but not to `point` in between,
+    /// although nothing in between assigns it.
+    LivenessGap { region: String, point: String, before: String, after: String },
+
+    /// `region` is live on entry to `point` (and maybe elsewhere), but
+    /// no borrow, outlives relation, call or destructor involves it.
+    UnusedLiveRegion { region: String, point: String },
+}
+
+/// Returns the lints for `input`: first the gaps, block by block, then
+/// the unused regions, in the order in which they first become live.
+pub fn lint(input: &Input) -> Vec<Lint> {
+    let mut lints = vec![];
+
+    for block in &input.blocks {
+        // the statements at which each region is live on entry
+        let mut live: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+        for (index, statement) in block.statements.iter().enumerate() {
+            for effect in &statement.effects {
+                if let Effect::LiveOnEntry { region } = effect {
+                    live.entry(region).or_insert_with(Vec::new).push(index);
+                }
+            }
+        }
+
+        for (region, indices) in live {
+            for pair in indices.windows(2) {
+                let (before, after) = (pair[0], pair[1]);
+                let assigned = (before..after).any(|index| {
+                    let point = format!("{}/{}", block.name, index);
+                    input
+                        .assigned_regions(&block.statements[index], &point)
+                        .iter()
+                        .any(|assigned| assigned == region)
+                });
+                if assigned {
+                    continue;
+                }
+                for index in before + 1..after {
+                    lints.push(Lint::LivenessGap {
+                        region: region.to_string(),
+                        point: format!("{}/{}", block.name, index),
+                        before: format!("{}/{}", block.name, before),
+                        after: format!("{}/{}", block.name, after),
+                    });
+                }
+            }
+        }
+    }
+
+    let referenced = input.referenced_regions();
+    let mut reported: Vec<&str> = vec![];
+    for block in &input.blocks {
+        for (index, statement) in block.statements.iter().enumerate() {
+            for effect in &statement.effects {
+                if let Effect::LiveOnEntry { region } = effect {
+                    if !referenced.contains(region) && !reported.contains(&region.as_str()) {
+                        reported.push(region);
+                        lints.push(Lint::UnusedLiveRegion {
+                            region: region.to_string(),
+                            point: format!("{}/{}", block.name, index),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    lints
+}
+
+impl Input {
+    /// The regions that data flows into in `statement`, or that a borrow
+    /// in it creates.
+    fn assigned_regions(&self, statement: &Statement, point: &str) -> Vec<String> {
+        let mut result = vec![];
+        for effect in &statement.effects {
+            match effect {
+                Effect::Borrow { region, .. } | Effect::Reserve { region, .. } => {
+                    result.push(region.clone())
+                }
+                Effect::Outlives { b, .. } => result.push(b.clone()),
+                Effect::Call { callee, args, dest } => {
+                    for (_time, _a, b) in self.call_outlives(callee, args, dest, point) {
+                        result.push(b);
+                    }
+                }
+                _ => {}
+            }
+        }
+        result
+    }
+
+    /// The regions involved in some borrow, outlives relation or call,
+    /// or in the type of a variable whose destructor may access them
+    /// (those that dropping the variable keeps live).
+    fn referenced_regions(&self) -> Vec<String> {
+        let mut result = vec![];
+        for variable in &self.variables {
+            for variable_region in &variable.regions {
+                if !variable_region.may_dangle {
+                    result.push(variable_region.region.clone());
+                }
+            }
+        }
+        for block in &self.blocks {
+            let mut calls = vec![];
+            for statement in &block.statements {
+                for effect in &statement.effects {
+                    match effect {
+                        Effect::Borrow { region, .. } | Effect::Reserve { region, .. } => {
+                            result.push(region.clone())
+                        }
+                        Effect::Outlives { a, b, .. } => {
+                            result.push(a.clone());
+                            result.push(b.clone());
+                        }
+                        Effect::Call { callee, args, dest } => calls.push((callee, args, dest)),
+                        _ => {}
+                    }
+                }
+            }
+            if let Terminator::Call { callee, args, dest, .. } = &block.terminator {
+                calls.push((callee, args, dest));
+            }
+
+            // (the point only matters for the names of the fresh regions)
+            for (callee, args, dest) in calls {
+                for (_time, a, b) in self.call_outlives(callee, args, dest, &block.name) {
+                    result.push(a);
+                    result.push(b);
+                }
+            }
+        }
+        result
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::LivenessGap { region, point, before, after } => write!(
+                f,
+                "`{}` is live on entry to `{}` and `{}`, but not to `{}`",
+                region, before, after, point
+            ),
+            Lint::UnusedLiveRegion { region, point } => write!(
+                f,
+                "`{}` is live on entry to `{}`, but no borrow or outlives relation involves it",
+                region, point
+            ),
+        }
+    }
+}
@@ -9,8 +9,12 @@ impl Input {
             for (index, statement) in block.statements.iter().enumerate() {
                 let point = &format!("{}/{}", block.name, index);
                 for effect in &statement.effects {
-                    if let Effect::Borrow { borrow, region } = effect {
-                        op(region, borrow, point)?;
+                    match effect {
+                        Effect::Borrow { borrow, region, .. }
+                        | Effect::Reserve { borrow, region, .. } => {
+                            op(region, borrow, point)?;
+                        }
+                        _ => {}
                     }
                 }
             }
@@ -47,8 +51,22 @@ impl Input {
     ) -> Result<(), E> {
         for block in &self.blocks {
             let term_point = &format!("{}/{}", block.name, block.statements.len());
-            for goto in &block.goto {
-                op(term_point, &format!("{}/0", goto))?;
+            for target in block.terminator.successors() {
+                op(term_point, &format!("{}/0", target))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Like `for_each_goto_fact`, but for the unwind edges.
+    crate fn for_each_unwind_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            let term_point = &format!("{}/{}", block.name, block.statements.len());
+            if let Some(unwind) = block.terminator.unwind() {
+                op(term_point, &format!("{}/0", unwind))?;
             }
         }
         Ok(())
@@ -75,12 +93,21 @@ impl Input {
         &self,
         mut op: impl FnMut(&str, &str) -> Result<(), E>,
     ) -> Result<(), E> {
+        let borrowed_places = self.borrowed_places();
         for block in &self.blocks {
             for (index, statement) in block.statements.iter().enumerate() {
                 let point = &format!("{}/{}", block.name, index);
                 for effect in &statement.effects {
-                    if let Effect::Kill { borrow } = effect {
-                        op(borrow, point)?;
+                    match effect {
+                        Effect::Kill { borrow } => op(borrow, point)?,
+                        Effect::Assign { place } => {
+                            for &(borrow, borrowed_place) in &borrowed_places {
+                                if place.is_prefix_of(borrowed_place) {
+                                    op(borrow, point)?;
+                                }
+                            }
+                        }
+                        _ => {}
                     }
                 }
             }
@@ -97,15 +124,422 @@ impl Input {
                 let point = &format!("{}/{}", block.name, index);
                 let successor_point = &format!("{}/{}", block.name, index + 1);
                 for effect in &statement.effects {
-                    if let Effect::Outlives { time, a, b } = effect {
-                        match time {
+                    match effect {
+                        Effect::Outlives { time, a, b } => match time {
                             OutlivesTime::Pre => op(point, a, b, point)?,
                             OutlivesTime::Post => op(point, a, b, successor_point)?,
+                        },
+                        Effect::Call { callee, args, dest } => {
+                            for (time, a, b) in self.call_outlives(callee, args, dest, point) {
+                                match time {
+                                    OutlivesTime::Pre => op(point, &a, &b, point)?,
+                                    OutlivesTime::Post => op(point, &a, &b, successor_point)?,
+                                }
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            // the destination of a call terminator is only assigned on
+            // the normal edge
+            if let Terminator::Call { callee, args, dest, target, .. } = &block.terminator {
+                let term_point = &format!("{}/{}", block.name, block.statements.len());
+                let target_point = &format!("{}/0", target);
+                for (time, a, b) in self.call_outlives(callee, args, dest, term_point) {
+                    match time {
+                        OutlivesTime::Pre => op(term_point, &a, &b, term_point)?,
+                        OutlivesTime::Post => op(term_point, &a, &b, target_point)?,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// The outlives relations implied by a call at `point`: the regions
+    /// of each argument outlive the corresponding regions of the
+    /// parameter type on entry, and the regions of the return type
+    /// outlive those of `dest` on exit. Each call gets its own copy of
+    /// the lifetime parameters, named `callee::'a@point`; `'static` and
+    /// other lifetimes that are not parameters are ignored, as are calls
+    /// of functions without a signature (see `validate`).
+    crate fn call_outlives(
+        &self,
+        callee: &str,
+        args: &[String],
+        dest: &Option<String>,
+        point: &str,
+    ) -> Vec<(OutlivesTime, String, String)> {
+        let mut result = vec![];
+        let signature = match self.signatures.iter().find(|s| s.name == callee) {
+            Some(signature) => signature,
+            None => return result,
+        };
+        let fresh = |lifetime: &str| -> Option<String> {
+            if signature.lifetimes.iter().any(|l| l == lifetime) {
+                Some(format!("{}::'{}@{}", callee, lifetime, point))
+            } else {
+                None
+            }
+        };
+
+        for (arg, input) in args.iter().zip(&signature.inputs) {
+            let regions = self.variable_regions(arg);
+            for (region, lifetime) in regions.into_iter().zip(input.lifetimes()) {
+                if let Some(fresh) = fresh(lifetime) {
+                    result.push((OutlivesTime::Pre, region.to_string(), fresh));
+                }
+            }
+        }
+
+        if let (Some(dest), Some(output)) = (dest, &signature.output) {
+            let regions = self.variable_regions(dest);
+            for (region, lifetime) in regions.into_iter().zip(output.lifetimes()) {
+                if let Some(fresh) = fresh(lifetime) {
+                    result.push((OutlivesTime::Post, fresh, region.to_string()));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// The regions in the type of `variable`: those given by its `var`
+    /// declaration, or else just the region named after it.
+    crate fn variable_regions(&'a self, variable: &'a str) -> Vec<&'a str> {
+        match self.variables.iter().find(|v| v.name == variable) {
+            Some(v) => v.regions.iter().map(|r| r.region.as_str()).collect(),
+            None => vec![variable],
+        }
+    }
+
+    crate fn for_each_var_drop_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Drop { variable } = effect {
+                        op(variable, point)?;
+                    }
+                }
+            }
+
+            if let Terminator::Drop { variable, .. } = &block.terminator {
+                op(variable, &format!("{}/{}", block.name, block.statements.len()))?;
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_var_drop_region_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for variable in &self.variables {
+            for variable_region in &variable.regions {
+                if !variable_region.may_dangle {
+                    op(&variable.name, &variable_region.region)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_var_init_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Init { variable } = effect {
+                        op(variable, point)?;
+                    }
+                }
+            }
+        }
+
+        // variables that are never `init`ed are taken to be initialized
+        // (by the first statement of their function), like arguments
+        for variable in &self.variables {
+            if self.initializes(&variable.name) {
+                continue;
+            }
+            if let Some(entry) = self.entry_block(&variable.name) {
+                op(&variable.name, &format!("{}/0", entry.name))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// True if some `init` effect initializes `variable`.
+    fn initializes(&self, variable: &str) -> bool {
+        self.blocks.iter().any(|block| {
+            block.statements.iter().any(|statement| {
+                statement.effects.iter().any(|effect| match effect {
+                    Effect::Init { variable: v } => v == variable,
+                    _ => false,
+                })
+            })
+        })
+    }
+
+    /// The first block of the function declaring `variable`. In an
+    /// input merged from several functions (see `Program::merged`),
+    /// that is the first block with the same `foo::` prefix.
+    fn entry_block(&self, variable: &str) -> Option<&Block> {
+        self.blocks
+            .iter()
+            .find(|block| namespace(&block.name) == namespace(variable))
+    }
+
+    crate fn for_each_var_moved_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Move { variable } = effect {
+                        op(variable, point)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_borrow_reserved_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Reserve { borrow, .. } = effect {
+                        op(borrow, point)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_borrow_activated_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Activate { borrow } = effect {
+                        op(borrow, point)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_mut_borrow_fact<E>(
+        &self,
+        mut op: impl FnMut(&str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        for block in &self.blocks {
+            for statement in &block.statements {
+                for effect in &statement.effects {
+                    match effect {
+                        Effect::Borrow { borrow, kind: BorrowKind::Mut, .. }
+                        | Effect::Reserve { borrow, .. } => {
+                            op(borrow)?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    crate fn for_each_borrowed_path_read_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let borrowed_places = self.borrowed_places();
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    if let Effect::Read { place } = effect {
+                        for &(borrow, borrowed_place) in &borrowed_places {
+                            if place.overlaps(borrowed_place) {
+                                op(borrow, point)?;
+                            }
                         }
                     }
                 }
             }
+
+            // a switch reads its discriminant
+            if let Terminator::Switch { place, .. } = &block.terminator {
+                let term_point = &format!("{}/{}", block.name, block.statements.len());
+                for &(borrow, borrowed_place) in &borrowed_places {
+                    if place.overlaps(borrowed_place) {
+                        op(borrow, term_point)?;
+                    }
+                }
+            }
         }
         Ok(())
     }
+
+    crate fn for_each_borrowed_path_written_fact<E>(
+        &self,
+        mut op: impl FnMut(&str, &str) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let borrowed_places = self.borrowed_places();
+        for block in &self.blocks {
+            for (index, statement) in block.statements.iter().enumerate() {
+                let point = &format!("{}/{}", block.name, index);
+                for effect in &statement.effects {
+                    for &(borrow, borrowed_place) in &borrowed_places {
+                        let conflicts = match effect {
+                            Effect::Write { place } => place.overlaps(borrowed_place),
+                            Effect::Assign { place } => {
+                                place.assignment_conflicts_with(borrowed_place)
+                            }
+                            _ => false,
+                        };
+                        if conflicts {
+                            op(borrow, point)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// All borrows whose borrowed path is known, along with that path.
+    fn borrowed_places(&self) -> Vec<(&str, &Place)> {
+        let mut result = vec![];
+        for block in &self.blocks {
+            for statement in &block.statements {
+                for effect in &statement.effects {
+                    match effect {
+                        Effect::Borrow { borrow, place: Some(place), .. }
+                        | Effect::Reserve { borrow, place: Some(place), .. } => {
+                            result.push((borrow.as_str(), place));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl Type {
+    /// The lifetimes appearing in this type, from left to right.
+    crate fn lifetimes(&self) -> Vec<&str> {
+        let mut result = vec![];
+        self.push_lifetimes(&mut result);
+        result
+    }
+
+    fn push_lifetimes(&'a self, result: &mut Vec<&'a str>) {
+        match self {
+            Type::Ref { lifetime, referent, .. } => {
+                result.push(lifetime);
+                referent.push_lifetimes(result);
+            }
+            Type::Path { args, .. } => {
+                for arg in args {
+                    match arg {
+                        GenericArg::Lifetime(lifetime) => result.push(lifetime),
+                        GenericArg::Type(ty) => ty.push_lifetimes(result),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum Projection<'a> {
+    Deref,
+    Field(&'a str),
+}
+
+impl Place {
+    /// The local at the root of this place, along with the projections
+    /// applied to it (innermost first); e.g., `x` and `[*, .f]` for
+    /// `(*x).f`.
+    fn local_and_projections(&'a self) -> (&'a str, Vec<Projection<'a>>) {
+        match self {
+            Place::Local(local) => (local.as_str(), vec![]),
+            Place::Deref(base) => {
+                let (local, mut projections) = base.local_and_projections();
+                projections.push(Projection::Deref);
+                (local, projections)
+            }
+            Place::Field(base, field) => {
+                let (local, mut projections) = base.local_and_projections();
+                projections.push(Projection::Field(field.as_str()));
+                (local, projections)
+            }
+        }
+    }
+
+    /// True if `self` is a prefix of `other` (e.g., `x` is a prefix of
+    /// `x`, `x.f` and `(*x).f`).
+    fn is_prefix_of(&self, other: &Place) -> bool {
+        let (local_a, projections_a) = self.local_and_projections();
+        let (local_b, projections_b) = other.local_and_projections();
+        local_a == local_b && projections_b.starts_with(&projections_a)
+    }
+
+    /// True if accessing `self` may access data reachable from `other`
+    /// or vice versa.
+    fn overlaps(&self, other: &Place) -> bool {
+        self.is_prefix_of(other) || other.is_prefix_of(self)
+    }
+
+    /// True if overwriting `self` conflicts with a borrow of `borrowed`.
+    /// This is like `overlaps`, except that if `borrowed` is reached by
+    /// dereferencing `self`, the borrowed data is not affected by the
+    /// assignment (the borrow is merely killed).
+    fn assignment_conflicts_with(&self, borrowed: &Place) -> bool {
+        let (local_a, projections_a) = self.local_and_projections();
+        let (local_b, projections_b) = borrowed.local_and_projections();
+        if local_a != local_b {
+            return false;
+        }
+
+        if projections_a.starts_with(&projections_b) {
+            return true;
+        }
+
+        projections_b.starts_with(&projections_a)
+            && !projections_b[projections_a.len()..].contains(&Projection::Deref)
+    }
+}
+
+/// The `foo::` prefix that `Program::merged` gives to the names in
+/// function `foo`, or `""` for an unnamed function.
+fn namespace(name: &str) -> &str {
+    match name.rfind("::") {
+        Some(index) => &name[..index + 2],
+        None => "",
+    }
 }
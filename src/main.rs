@@ -1,5 +1,4 @@
 #![feature(catch_expr)]
-#![feature(crate_in_paths)]
 #![feature(crate_visibility_modifier)]
 #![feature(dyn_trait)]
 #![feature(in_band_lifetimes)]
@@ -8,175 +7,628 @@
 
 #[cfg(test)]
 extern crate assert_cli;
-
+extern crate nll_souffle;
+#[macro_use]
+extern crate serde_json;
 #[macro_use]
-extern crate abomonation_derive;
-extern crate abomonation;
-extern crate differential_dataflow;
-extern crate timely;
-
-mod ir;
-mod facts;
-mod intern;
-mod lower;
-mod parser;
-mod solve;
-mod tab_delim;
+extern crate structopt;
+
+mod annotate;
+mod check;
+mod compare;
+mod dot;
+mod facts_to_ir;
+mod generate;
+mod reduce;
+mod snapshot;
 mod tests;
 
+use nll_souffle::{intern, ir, json, lint, parser, solve, tab_delim, InvalidInput};
 use std::env;
 use std::error::Error;
 use std::fs::File;
+use std::io;
 use std::io::prelude::*;
 use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::Instant;
+use structopt::clap::ErrorKind;
+use structopt::StructOpt;
+
+/// Location-sensitive region inference for NLL
+#[derive(StructOpt, Debug)]
+#[structopt(name = "nll-souffle")]
+enum Command {
+    /// Writes the facts for each input next to it, for souffle
+    #[structopt(name = "dump")]
+    Dump {
+        /// Ignore the unwind edges of `call` and `drop` terminators
+        #[structopt(long = "no-unwind")]
+        no_unwind: bool,
+
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Solves each input with differential-dataflow
+    #[structopt(name = "solve")]
+    Solve {
+        /// Solve the functions of each input in parallel
+        #[structopt(long = "parallel")]
+        parallel: bool,
+
+        /// Propagate liveness across unwind edges
+        #[structopt(long = "unwind-liveness")]
+        unwind_liveness: bool,
+
+        /// Derive liveness at the end of blocks, and warn about
+        /// liveness that does not match
+        #[structopt(long = "derive-liveness")]
+        derive_liveness: bool,
+
+        /// Print an annotated listing instead of the flat results
+        #[structopt(long = "annotate")]
+        annotate: bool,
+
+        /// How to print the results (`--annotate` requires `text`)
+        #[structopt(
+            long = "format",
+            default_value = "text",
+            raw(possible_values = r#"&["text", "json"]"#)
+        )]
+        format: Format,
+
+        /// Ignore the unwind edges of `call` and `drop` terminators
+        #[structopt(long = "no-unwind")]
+        no_unwind: bool,
+
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Solves the `.facts` files in each directory, as written by
+    /// `dump` or by rustc's `-Znll-facts`
+    #[structopt(name = "solve-facts")]
+    SolveFacts {
+        #[structopt(name = "DIR", raw(required = "true"))]
+        dirs: Vec<String>,
+    },
+
+    /// Checks the assertions in each input
+    #[structopt(name = "check")]
+    Check {
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Looks for likely mistakes in the liveness of each input
+    #[structopt(name = "lint")]
+    Lint {
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Prints the control-flow graph of each input in graphviz format
+    #[structopt(name = "dot")]
+    Dot {
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Rewrites each input in canonical form
+    #[structopt(name = "fmt")]
+    Fmt {
+        #[structopt(name = "FILE", raw(required = "true"))]
+        files: Vec<String>,
+    },
+
+    /// Reconstructs an input from the `.facts` files in each directory
+    #[structopt(name = "facts-to-ir")]
+    FactsToIr {
+        #[structopt(name = "DIR", raw(required = "true"))]
+        dirs: Vec<String>,
+    },
+
+    /// Compares the results of souffle and differential-dataflow on
+    /// the `.facts` files in each directory
+    #[structopt(name = "compare")]
+    Compare {
+        #[structopt(name = "DIR", raw(required = "true"))]
+        dirs: Vec<String>,
+    },
+
+    /// Updates the expected results of each snapshot test directory
+    #[structopt(name = "bless")]
+    Bless {
+        #[structopt(name = "DIR", raw(required = "true"))]
+        dirs: Vec<String>,
+    },
+
+    /// Prints a random input
+    #[structopt(name = "generate")]
+    Generate {
+        seed: u64,
+
+        /// The number of blocks
+        blocks: Option<usize>,
+    },
+
+    /// Prints the smallest input we can find for which the given
+    /// predicate holds
+    #[structopt(name = "reduce")]
+    Reduce {
+        /// `borrowLiveAt` contains BORROW at POINT
+        #[structopt(
+            long = "live",
+            raw(number_of_values = "2", value_names = r#"&["BORROW", "POINT"]"#)
+        )]
+        live: Vec<String>,
+
+        /// `errors` contains BORROW at POINT
+        #[structopt(
+            long = "error",
+            raw(number_of_values = "2", value_names = r#"&["BORROW", "POINT"]"#)
+        )]
+        error: Vec<String>,
+
+        /// souffle and differential-dataflow disagree
+        #[structopt(long = "disagree")]
+        disagree: bool,
+
+        /// The shell command COMMAND succeeds when given the path of a
+        /// file containing the input as its last argument
+        #[structopt(long = "command")]
+        command: Option<String>,
+
+        /// The function to reduce, if the input has several
+        #[structopt(long = "function")]
+        function: Option<String>,
+
+        #[structopt(name = "FILE")]
+        file: String,
+    },
+}
+
+/// The exit status when the analysis of some input fails, e.g. when an
+/// assertion does not hold or there are lints. When several inputs fail
+/// in different ways, the highest status wins.
+const EXIT_FAILED: i32 = 1;
+
+/// The exit status when some input is invalid (or so is the command
+/// line), e.g. because it cannot be parsed.
+const EXIT_INVALID: i32 = 2;
+
+/// The exit status when reading or writing a file fails.
+const EXIT_IO: i32 = 3;
 
 fn main() {
-    let parser = parser::InputParser::new();
+    let command = match Command::clap().get_matches_safe() {
+        Ok(matches) => Command::from_clap(&matches),
+        Err(err) => match err.kind {
+            // (prints to stdout and exits successfully)
+            ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => err.exit(),
+            _ => {
+                eprintln!("{}", err.message);
+                process::exit(EXIT_INVALID);
+            }
+        },
+    };
 
-    let mut args = env::args().skip(1).peekable();
+    let exit_code = match command {
+        Command::Dump { no_unwind, files } => for_each_input(&files, |input_file| {
+            let mut program = json::read_program(Path::new(input_file))?;
+            if no_unwind {
+                program.remove_unwind_edges();
+            }
+            dump_facts(input_file, &program)?;
+            Ok(true)
+        }),
 
-    if args.peek().map_or(false, |arg| arg == "--execute-from-facts") {
-        args.next();
-        for input_dir in args {
-            execute_from_facts(&Path::new(&input_dir)).unwrap();
+        Command::Solve {
+            parallel,
+            unwind_liveness,
+            derive_liveness,
+            annotate,
+            format,
+            no_unwind,
+            files,
+        } => {
+            if annotate && format != Format::Text {
+                eprintln!("error: `--annotate` requires `--format text`");
+                process::exit(EXIT_INVALID);
+            }
+            let options = solve::SolveOptions {
+                parallel,
+                unwind_liveness,
+                derive_liveness,
+            };
+            for_each_input(&files, |input_file| {
+                let mut program = json::read_program(Path::new(input_file))?;
+                if no_unwind {
+                    program.remove_unwind_edges();
+                }
+                solve_facts(input_file, &program, &options, annotate, format)?;
+                Ok(true)
+            })
         }
-        return;
-    }
 
-    let mut execute_mode = false;
-    if args.peek().map_or(false, |arg| arg == "--execute") {
-        args.next();
-        execute_mode = true;
-    }
+        Command::SolveFacts { dirs } => for_each_input(&dirs, |facts_dir| {
+            execute_from_facts(Path::new(facts_dir))?;
+            Ok(true)
+        }),
+
+        Command::Check { files } => for_each_input(&files, check_assertions),
+
+        Command::Lint { files } => for_each_input(&files, lint_input),
+
+        Command::Dot { files } => for_each_input(&files, |input_file| {
+            let program = json::read_program(Path::new(input_file))?;
+            print!("{}", dot::dot(input_file, &program.merged()));
+            Ok(true)
+        }),
+
+        Command::Fmt { files } => for_each_input(&files, |input_file| {
+            format_in_place(input_file)?;
+            Ok(true)
+        }),
+
+        Command::FactsToIr { dirs } => for_each_input(&dirs, |facts_dir| {
+            print_ir_from_facts(Path::new(facts_dir))?;
+            Ok(true)
+        }),
 
-    for input_file in args {
-        let mut input_text = &mut String::new();
-        let result: Result<(), Box<dyn Error>> = do catch {
-            let mut file = File::open(&input_file)?;
-            file.read_to_string(input_text)?;
-            let ir = parser.parse(input_text)?;
-
-            if execute_mode {
-                solve_facts(&ir)
-            } else {
-                dump_facts(&input_file, &ir)
+        Command::Compare { dirs } => for_each_input(&dirs, |facts_dir| {
+            let differences = match compare::compare(Path::new(facts_dir))? {
+                Some(differences) => differences,
+                None => {
+                    eprintln!("`{}`: skipped, as `souffle` is not installed", facts_dir);
+                    return Ok(true);
+                }
+            };
+            if differences.is_empty() {
+                println!("`{}`: souffle and differential-dataflow agree", facts_dir);
             }
-        };
+            for difference in &differences {
+                difference.dump();
+            }
+            Ok(differences.is_empty())
+        }),
+
+        Command::Bless { dirs } => for_each_input(&dirs, |test_dir| {
+            snapshot::check_snapshot(Path::new(test_dir), true)?;
+            Ok(true)
+        }),
+
+        Command::Generate { seed, blocks } => {
+            let mut options = generate::GenerateOptions::default();
+            if let Some(blocks) = blocks {
+                options.blocks = blocks;
+            }
+            if options.blocks == 0 {
+                eprintln!("error: the number of blocks must be positive");
+                process::exit(EXIT_INVALID);
+            }
+            print!("{}", generate::generate(seed, &options));
+            0
+        }
 
-        match result {
-            Ok(()) => {}
+        Command::Reduce {
+            live,
+            error,
+            disagree,
+            command,
+            function,
+            file,
+        } => {
+            let mut predicates = vec![];
+            if let [borrow, point] = &live[..] {
+                predicates.push(reduce::Predicate::BorrowLiveAt {
+                    borrow: borrow.clone(),
+                    point: point.clone(),
+                });
+            }
+            if let [borrow, point] = &error[..] {
+                predicates.push(reduce::Predicate::Error {
+                    borrow: borrow.clone(),
+                    point: point.clone(),
+                });
+            }
+            if disagree {
+                predicates.push(reduce::Predicate::Disagree);
+            }
+            if let Some(command) = command {
+                predicates.push(reduce::Predicate::Command(command));
+            }
+            if predicates.len() != 1 || live.len() > 2 || error.len() > 2 {
+                eprintln!(
+                    "error: `reduce` takes exactly one of `--live`, `--error`, `--disagree` \
+                     and `--command`"
+                );
+                process::exit(EXIT_INVALID);
+            }
+            for_each_input(&[file], |input_file| {
+                reduce_input(input_file, function.as_ref().map(|f| f.as_str()), &predicates[0])?;
+                Ok(true)
+            })
+        }
+    };
+
+    process::exit(exit_code);
+}
+
+/// Runs `action` on each of `inputs`, carrying on after failures, and
+/// returns the exit status: the action returns false if the analysis
+/// failed (e.g. an assertion does not hold), and errors are reported as
+/// they occur. With more than one input, a summary follows.
+fn for_each_input(
+    inputs: &[String],
+    mut action: impl FnMut(&str) -> Result<bool, Box<dyn Error>>,
+) -> i32 {
+    let mut exit_code = 0;
+    let mut num_failed = 0;
+    for input in inputs {
+        let input_exit_code = match action(input) {
+            Ok(true) => 0,
+            Ok(false) => EXIT_FAILED,
             Err(err) => {
-                eprintln!("`{}`: {}", input_file, err);
+                eprintln!("`{}`: {}", input, err);
+                if err.is::<io::Error>() {
+                    EXIT_IO
+                } else if err.is::<InvalidInput>() {
+                    EXIT_INVALID
+                } else {
+                    EXIT_FAILED
+                }
             }
+        };
+        if input_exit_code != 0 {
+            num_failed += 1;
         }
+        exit_code = exit_code.max(input_exit_code);
     }
+    if inputs.len() > 1 {
+        eprintln!(
+            "{} of {} inputs succeeded, {} failed",
+            inputs.len() - num_failed,
+            inputs.len(),
+            num_failed
+        );
+    }
+    exit_code
 }
 
-fn execute_from_facts(
-    facts_dir: &Path,
-) -> Result<(), Box<dyn Error>> {
+fn execute_from_facts(facts_dir: &Path) -> Result<(), Box<dyn Error>> {
     let tables = &mut intern::InternerTables::new();
-    let all_facts = tab_delim::load_tab_delimited_facts(tables, facts_dir);
-    Ok(solve::region_computation_from_facts(tables, all_facts))
+    let all_facts = if facts_dir.join("cfg_edge.facts").exists() {
+        // looks like the output of rustc's `-Znll-facts`
+        tab_delim::load_rustc_nll_facts(tables, facts_dir)?
+    } else {
+        tab_delim::load_tab_delimited_facts(tables, facts_dir)?
+    };
+    solve::region_computation_from_facts(tables, all_facts);
+    Ok(())
 }
 
-fn write_to(
-    path: &Path,
-    output: impl FnOnce(&mut File) -> Result<(), Box<dyn Error>>,
+fn print_ir_from_facts(facts_dir: &Path) -> Result<(), Box<dyn Error>> {
+    let tables = &mut intern::InternerTables::new();
+    let all_facts = tab_delim::load_tab_delimited_facts(tables, facts_dir)?;
+    let mut ir = facts_to_ir::facts_to_ir(tables, &all_facts).map_err(InvalidInput)?;
+    ir.comments
+        .insert(0, format!("// reconstructed from `{}`", facts_dir.display()));
+    print!("{}", ir);
+    Ok(())
+}
+
+/// Rewrites `input_file` in canonical form.
+fn format_in_place(input_file: &str) -> Result<(), Box<dyn Error>> {
+    if json::is_json(Path::new(input_file)) {
+        let message = "only inputs in the input language can be formatted, not JSON";
+        return Err(InvalidInput(message.to_string()).into());
+    }
+    let mut input_text = String::new();
+    File::open(input_file)?.read_to_string(&mut input_text)?;
+    let program = parser::ProgramParser::new()
+        .parse(&input_text)
+        .map_err(|err| InvalidInput(err.to_string()))?;
+    program.validate().map_err(InvalidInput)?;
+    let formatted = program.to_string();
+    if formatted != input_text {
+        tab_delim::write_to(Path::new(input_file), |file| {
+            Ok(file.write_all(formatted.as_bytes())?)
+        })?;
+    }
+    Ok(())
+}
+
+/// Prints the assertions in `input_file` that do not hold; returns
+/// true if there are none.
+fn check_assertions(input_file: &str) -> Result<bool, Box<dyn Error>> {
+    let program = json::read_program(Path::new(input_file))?;
+    let (failures, num_assertions) = check::check(&program.merged());
+    for failure in &failures {
+        println!(
+            "`{}`: {}: `{}` does not hold",
+            input_file, failure.point, failure.assertion
+        );
+    }
+    println!(
+        "`{}`: {} of {} assertions hold",
+        input_file,
+        num_assertions - failures.len(),
+        num_assertions
+    );
+    Ok(failures.is_empty())
+}
+
+/// Prints the lints for `input_file`; returns true if there are none.
+fn lint_input(input_file: &str) -> Result<bool, Box<dyn Error>> {
+    let program = json::read_program(Path::new(input_file))?;
+    let lints = lint::lint(&program.merged());
+    for lint in &lints {
+        println!("`{}`: {}", input_file, lint);
+    }
+    Ok(lints.is_empty())
+}
+
+/// Prints the smallest input we can find for which `predicate` holds,
+/// starting from the function `function` of `input_file` (which may be
+/// left out if there is only one).
+fn reduce_input(
+    input_file: &str,
+    function: Option<&str>,
+    predicate: &reduce::Predicate,
 ) -> Result<(), Box<dyn Error>> {
-    let mut file = File::create(path)?;
-    output(&mut file)?;
+    let program = json::read_program(Path::new(input_file))?;
+    let index = match function {
+        Some(name) => program
+            .functions
+            .iter()
+            .position(|f| f.name.as_ref().map(|n| n.as_str()) == Some(name))
+            .ok_or_else(|| InvalidInput(format!("there is no function `{}`", name)))?,
+        None if program.functions.len() == 1 => 0,
+        None => {
+            let message = "there are several functions; pick one with `--function`";
+            return Err(InvalidInput(message.to_string()).into());
+        }
+    };
+    let input = &program.function_inputs()[index];
+    print!("{}", reduce::reduce(input, predicate).map_err(InvalidInput)?);
     Ok(())
 }
 
-fn solve_facts(ir: &ir::Input) -> Result<(), Box<dyn Error>> {
-    Ok(solve::region_computation(ir))
+/// How `solve` prints the results.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Format {
+    /// `vvv borrowLiveAt vvv` and so forth (see `Output::dump`), or an
+    /// annotated listing.
+    Text,
+
+    /// One JSON object per line and function, with all relations and
+    /// some metadata (see `json_results`).
+    Json,
 }
 
-fn dump_facts(input_file: &String, ir: &ir::Input) -> Result<(), Box<dyn Error>> {
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(format: &str) -> Result<Self, String> {
+        match format {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{}`", format)),
+        }
+    }
+}
+
+fn solve_facts(
+    input_file: &str,
+    program: &ir::Program,
+    options: &solve::SolveOptions,
+    annotate: bool,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let instant = Instant::now();
+    let results = solve::solve_program(program, options);
+    let duration = instant.elapsed();
+    let duration_secs = duration.as_secs() as f64 + f64::from(duration.subsec_nanos()) * 1e-9;
+    if format == Format::Text {
+        println!("duration: {}.{:09}s", duration.as_secs(), duration.subsec_nanos());
+    }
+
+    for (function, (tables, output)) in program.functions.iter().zip(&results) {
+        if format == Format::Json {
+            let results = json_results(input_file, &function.name, duration_secs, tables, output);
+            println!("{}", results);
+            continue;
+        }
+
+        if let Some(name) = &function.name {
+            println!("--- fn {} ---", name);
+        }
+        for &(region, point, successor) in &output.liveness_warnings {
+            eprintln!(
+                "warning: region `{}` is live at `{}` but not at `{}`, which does not assign it",
+                tables.regions.untern(region),
+                tables.points.untern(successor),
+                tables.points.untern(point),
+            );
+        }
+        if annotate {
+            print!("{}", annotate::Annotated::new(&function.input, tables, output));
+        } else {
+            output.dump(tables);
+        }
+    }
+    Ok(())
+}
+
+/// The results for one function, as
+///
+/// ```json
+/// {
+///   "metadata": {
+///     "input": "tests/issue-47680/test.txt",
+///     "function": null,
+///     "algorithm": "differential-dataflow",
+///     "duration": 0.012,
+///     "counts": { "borrowLiveAt": 3, ... },
+///     "warnings": []
+///   },
+///   "relations": { "borrowLiveAt": [["B_x", "B/1"], ...], ... }
+/// }
+/// ```
+///
+/// where `duration` is the time in seconds taken to solve the whole
+/// file, and `warnings` lists `Output::liveness_warnings`.
+fn json_results(
+    input_file: &str,
+    function: &Option<String>,
+    duration_secs: f64,
+    tables: &intern::InternerTables,
+    output: &solve::Output,
+) -> serde_json::Value {
+    let relations = output.all_relations(tables);
+    let counts: serde_json::Map<String, serde_json::Value> = relations
+        .iter()
+        .map(|(name, tuples)| (name.to_string(), json!(tuples.len())))
+        .collect();
+    let relations: serde_json::Map<String, serde_json::Value> = relations
+        .into_iter()
+        .map(|(name, tuples)| (name.to_string(), json!(tuples)))
+        .collect();
+    let warnings: Vec<Vec<&str>> = output
+        .liveness_warnings
+        .iter()
+        .map(|&(region, point, successor)| {
+            vec![
+                tables.regions.untern(region),
+                tables.points.untern(point),
+                tables.points.untern(successor),
+            ]
+        })
+        .collect();
+
+    json!({
+        "metadata": {
+            "input": input_file,
+            "function": function,
+            "algorithm": "differential-dataflow",
+            "duration": duration_secs,
+            "counts": counts,
+            "warnings": warnings,
+        },
+        "relations": relations,
+    })
+}
+
+/// Writes the facts for all functions of `program`, namespaced by
+/// function (see `Program::merged`), next to `input_file`.
+fn dump_facts(input_file: &str, program: &ir::Program) -> Result<(), Box<dyn Error>> {
     let path = PathBuf::from(input_file);
     let parent_path = match path.parent() {
         Some(p) => p.to_owned(),
         None => env::current_dir().unwrap(),
     };
-
-    write_to(&parent_path.join("borrowRegion.facts"), |file| {
-        ir.for_each_borrow_region_fact(|region, borrow, point| {
-            write!(
-                file,
-                "\"{region}\"\t\"{borrow}\"\t\"{point}\"\n",
-                region = region,
-                borrow = borrow,
-                point = point,
-            )
-        })?;
-        Ok(())
-    })?;
-
-    write_to(&parent_path.join("nextStatement.facts"), |file| {
-        ir.for_each_next_statement_fact(|prev_point, point| {
-            write!(
-                file,
-                "\"{prev_point}\"\t\"{point}\"\n",
-                prev_point = prev_point,
-                point = point,
-            )
-        })?;
-        Ok(())
-    })?;
-
-    write_to(&parent_path.join("goto.facts"), |file| {
-        ir.for_each_goto_fact(|prev_point, point| {
-            write!(
-                file,
-                "\"{prev_point}\"\t\"{point}\"\n",
-                prev_point = prev_point,
-                point = point,
-            )
-        })?;
-        Ok(())
-    })?;
-
-    write_to(
-        &parent_path.join("regionLiveOnEntryToStatement.facts"),
-        |file| {
-            ir.for_each_region_live_on_entry_fact(|region, point| {
-                write!(
-                    file,
-                    "\"{region}\"\t\"{point}\"\n",
-                    region = region,
-                    point = point,
-                )
-            })?;
-            Ok(())
-        },
-    )?;
-
-    write_to(&parent_path.join("killed.facts"), |file| {
-        ir.for_each_killed_fact(|borrow, point| {
-            write!(
-                file,
-                "\"{borrow}\"\t\"{point}\"\n",
-                borrow = borrow,
-                point = point,
-            )
-        })?;
-        Ok(())
-    })?;
-
-    write_to(&parent_path.join("outlives.facts"), |file| {
-        ir.for_each_outlives_fact(|p, a, b, q| {
-            write!(
-                file,
-                "\"{p}\"\t\"{a}\"\t\"{b}\"\t\"{q}\"\n",
-                p = p,
-                a = a,
-                b = b,
-                q = q,
-            )
-        })?;
-        Ok(())
-    })?;
-
-    Ok(())
+    tab_delim::write_tab_delimited_facts(&program.merged(), &parent_path)
 }
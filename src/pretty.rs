@@ -0,0 +1,345 @@
+//! Pretty-printing of the IR, in the syntax accepted by the parser.
+//! The output is canonical: parsing and printing it again yields the
+//! same text, with effects sorted in the order given by
+//! `Effect::canonical_order`.
+
+use ir::*;
+use std::fmt;
+
+impl fmt::Display for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.comments {
+            writeln!(f, "{}", comment)?;
+        }
+
+        for (index, signature) in self.signatures.iter().enumerate() {
+            if index == 0 && !self.comments.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", signature)?;
+        }
+
+        for (index, function) in self.functions.iter().enumerate() {
+            if index > 0 || !self.comments.is_empty() || !self.signatures.is_empty() {
+                writeln!(f)?;
+            }
+            write!(f, "{}", function)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.name {
+            None => write!(f, "{}", self.input),
+            Some(name) => {
+                writeln!(f, "fn {} {{", name)?;
+                for line in self.input.to_string().lines() {
+                    if line.is_empty() {
+                        writeln!(f)?;
+                    } else {
+                        writeln!(f, "  {}", line)?;
+                    }
+                }
+                writeln!(f, "}}")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.comments {
+            writeln!(f, "{}", comment)?;
+        }
+
+        for (index, signature) in self.signatures.iter().enumerate() {
+            if index == 0 && !self.comments.is_empty() {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", signature)?;
+        }
+
+        for (index, variable) in self.variables.iter().enumerate() {
+            if index == 0 && (!self.comments.is_empty() || !self.signatures.is_empty()) {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", variable)?;
+        }
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            if index > 0
+                || !self.comments.is_empty()
+                || !self.signatures.is_empty()
+                || !self.variables.is_empty()
+            {
+                writeln!(f)?;
+            }
+            write!(f, "{}", block)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "sig {}", self.name)?;
+        if !self.lifetimes.is_empty() {
+            let lifetimes: Vec<String> = self.lifetimes.iter().map(|l| format!("'{}", l)).collect();
+            write!(f, "<{}>", lifetimes.join(", "))?;
+        }
+        let inputs: Vec<String> = self.inputs.iter().map(|ty| ty.to_string()).collect();
+        write!(f, "({})", inputs.join(", "))?;
+        if let Some(output) = &self.output {
+            write!(f, " -> {}", output)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Type::Ref { lifetime, mutable, referent } => {
+                write!(f, "&'{} {}{}", lifetime, if *mutable { "mut " } else { "" }, referent)
+            }
+            Type::Path { name, args } => {
+                write!(f, "{}", name)?;
+                if !args.is_empty() {
+                    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+                    write!(f, "<{}>", args.join(", "))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl fmt::Display for GenericArg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GenericArg::Lifetime(lifetime) => write!(f, "'{}", lifetime),
+            GenericArg::Type(ty) => write!(f, "{}", ty),
+        }
+    }
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "var {} {{", self.name)?;
+        for variable_region in &self.regions {
+            if variable_region.may_dangle {
+                write!(f, " may_dangle({})", variable_region.region)?;
+            } else {
+                write!(f, " {}", variable_region.region)?;
+            }
+        }
+        write!(f, " }}")
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "block {} {{", self.name)?;
+        for (index, statement) in self.statements.iter().enumerate() {
+            // separate commented statements from what came before
+            if index > 0 && !statement.comments.is_empty() {
+                writeln!(f)?;
+            }
+            write!(f, "{}", statement)?;
+        }
+        if !self.trailing_comments.is_empty() {
+            if !self.statements.is_empty() {
+                writeln!(f)?;
+            }
+            for comment in &self.trailing_comments {
+                writeln!(f, "  {}", comment)?;
+            }
+        }
+        if self.terminator != Terminator::default() {
+            writeln!(f, "  {}", self.terminator)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl fmt::Display for Terminator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Terminator::Goto { targets } => write!(f, "goto {{ {} }}", targets.join(" ")),
+            Terminator::Switch { place, targets } => {
+                write!(f, "switch({}) {{ {} }}", place, targets.join(" "))
+            }
+            Terminator::Call { callee, args, dest, target, unwind } => {
+                write!(f, "call({}", callee)?;
+                for arg in args {
+                    write!(f, ", {}", arg)?;
+                }
+                if let Some(dest) = dest {
+                    write!(f, " -> {}", dest)?;
+                }
+                write!(f, ") -> {}", target)?;
+                if let Some(unwind) = unwind {
+                    write!(f, " unwind {}", unwind)?;
+                }
+                Ok(())
+            }
+            Terminator::Drop { variable, target, unwind } => {
+                write!(f, "drop({}) -> {}", variable, target)?;
+                if let Some(unwind) = unwind {
+                    write!(f, " unwind {}", unwind)?;
+                }
+                Ok(())
+            }
+            Terminator::Return => write!(f, "return"),
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for comment in &self.comments {
+            writeln!(f, "  {}", comment)?;
+        }
+        writeln!(f, "  statement {{")?;
+        let mut effects: Vec<&Effect> = self.effects.iter().collect();
+        effects.sort_by_key(|effect| effect.canonical_order());
+        for effect in effects {
+            writeln!(f, "    {}", effect)?;
+        }
+        writeln!(f, "  }}")
+    }
+}
+
+impl Effect {
+    /// Effects within a statement are unordered, but we print them in
+    /// roughly the order in which they take place: first what holds or
+    /// is live on entry, then the borrows and accesses, and finally
+    /// what holds on exit.
+    crate fn canonical_order(&self) -> usize {
+        match self {
+            Effect::Assert { .. } => 0,
+            Effect::LiveOnEntry { .. } => 1,
+            Effect::Outlives { time: OutlivesTime::Pre, .. } => 2,
+            Effect::Borrow { .. } => 3,
+            Effect::Reserve { .. } => 4,
+            Effect::Activate { .. } => 5,
+            Effect::Call { .. } => 6,
+            Effect::Read { .. } => 7,
+            Effect::Write { .. } => 8,
+            Effect::Assign { .. } => 9,
+            Effect::Init { .. } => 10,
+            Effect::Move { .. } => 11,
+            Effect::Drop { .. } => 12,
+            Effect::Kill { .. } => 13,
+            Effect::Outlives { time: OutlivesTime::Post, .. } => 14,
+        }
+    }
+}
+
+impl Program {
+    /// See `Input::sort_effects`.
+    pub fn sort_effects(&mut self) {
+        for function in &mut self.functions {
+            function.input.sort_effects();
+        }
+    }
+}
+
+impl Input {
+    /// Sorts the effects of every statement into canonical order, so
+    /// that the result is exactly what parsing the printed form gives.
+    pub fn sort_effects(&mut self) {
+        for block in &mut self.blocks {
+            for statement in &mut block.statements {
+                statement
+                    .effects
+                    .sort_by_key(|effect| effect.canonical_order());
+            }
+        }
+    }
+}
+
+impl fmt::Display for Effect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Effect::Borrow { borrow, region, kind, place } => {
+                write!(f, "borrow({} as {}", borrow, region)?;
+                match (kind, place) {
+                    (BorrowKind::Mut, None) => {}
+                    (kind, None) => write!(f, ", {}", kind)?,
+                    (kind, Some(place)) => write!(f, ", {}, {}", kind, place)?,
+                }
+                write!(f, ")")
+            }
+            Effect::Reserve { borrow, region, place } => {
+                write!(f, "reserve({} as {}", borrow, region)?;
+                if let Some(place) = place {
+                    write!(f, ", {}", place)?;
+                }
+                write!(f, ")")
+            }
+            Effect::Activate { borrow } => write!(f, "activate({})", borrow),
+            Effect::LiveOnEntry { region } => write!(f, "liveOnEntry({})", region),
+            Effect::Kill { borrow } => write!(f, "kill({})", borrow),
+            Effect::Assign { place } => write!(f, "assign({})", place),
+            Effect::Read { place } => write!(f, "read({})", place),
+            Effect::Write { place } => write!(f, "write({})", place),
+            Effect::Outlives { time: OutlivesTime::Pre, a, b } => write!(f, "pre({}: {})", a, b),
+            Effect::Outlives { time: OutlivesTime::Post, a, b } => write!(f, "post({}: {})", a, b),
+            Effect::Drop { variable } => write!(f, "drop({})", variable),
+            Effect::Init { variable } => write!(f, "init({})", variable),
+            Effect::Move { variable } => write!(f, "move({})", variable),
+            Effect::Assert { assertion, negated } => {
+                write!(f, "assert {}{}", if *negated { "!" } else { "" }, assertion)
+            }
+            Effect::Call { callee, args, dest } => {
+                write!(f, "call({}", callee)?;
+                for arg in args {
+                    write!(f, ", {}", arg)?;
+                }
+                if let Some(dest) = dest {
+                    write!(f, " -> {}", dest)?;
+                }
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Assertion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Assertion::Live { borrow } => write!(f, "live({})", borrow),
+            Assertion::Error { borrow } => write!(f, "error({})", borrow),
+        }
+    }
+}
+
+impl fmt::Display for Place {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Place::Local(local) => write!(f, "{}", local),
+            Place::Deref(base) => write!(f, "*{}", base),
+
+            // `*x.f` would be `*(x.f)`, so we need parentheses here
+            Place::Field(base, field) => match **base {
+                Place::Deref(_) => write!(f, "({}).{}", base, field),
+                _ => write!(f, "{}.{}", base, field),
+            },
+        }
+    }
+}
+
+impl fmt::Display for BorrowKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BorrowKind::Shared => write!(f, "shared"),
+            BorrowKind::Mut => write!(f, "mut"),
+        }
+    }
+}
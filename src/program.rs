@@ -0,0 +1,142 @@
+//! Programs with several functions. Each function is solved on its
+//! own, but to lower a whole program into a single set of facts, we
+//! first merge its functions into one input, prefixing all names in
+//! function `foo` with `foo::` so that the functions stay apart.
+//! Signatures are shared, so their names are left alone.
+
+use ir::*;
+
+impl Program {
+    /// The input of each function, along with the signatures declared
+    /// at the top of the program.
+    pub fn function_inputs(&self) -> Vec<Input> {
+        self.functions
+            .iter()
+            .map(|function| {
+                let mut input = function.input.clone();
+                let mut signatures = self.signatures.clone();
+                for signature in input.signatures {
+                    if !signatures.contains(&signature) {
+                        signatures.push(signature);
+                    }
+                }
+                input.signatures = signatures;
+                input
+            })
+            .collect()
+    }
+
+    /// Combines all functions into a single input (see above).
+    pub fn merged(&self) -> Input {
+        let mut merged = Input {
+            comments: self.comments.clone(),
+            signatures: self.signatures.clone(),
+            variables: vec![],
+            blocks: vec![],
+        };
+        for function in &self.functions {
+            let input = match &function.name {
+                Some(name) => function.input.namespaced(&format!("{}::", name)),
+                None => function.input.clone(),
+            };
+            merged.comments.extend(input.comments);
+            for signature in input.signatures {
+                if !merged.signatures.contains(&signature) {
+                    merged.signatures.push(signature);
+                }
+            }
+            merged.variables.extend(input.variables);
+            merged.blocks.extend(input.blocks);
+        }
+        merged
+    }
+}
+
+impl Input {
+    /// Prefixes the names of all blocks, variables, regions, borrows
+    /// and locals with `prefix`; callees and signatures keep their
+    /// names.
+    fn namespaced(&self, prefix: &str) -> Input {
+        let name = &|original: &str| format!("{}{}", prefix, original);
+        let mut input = self.clone();
+        for variable in &mut input.variables {
+            variable.name = name(&variable.name);
+            for variable_region in &mut variable.regions {
+                variable_region.region = name(&variable_region.region);
+            }
+        }
+        for block in &mut input.blocks {
+            block.name = name(&block.name);
+            match &mut block.terminator {
+                Terminator::Switch { place, .. } => place.rename(name),
+                Terminator::Call { args, dest, .. } => {
+                    for arg in args {
+                        *arg = name(arg);
+                    }
+                    if let Some(dest) = dest {
+                        *dest = name(dest);
+                    }
+                }
+                Terminator::Drop { variable, .. } => *variable = name(variable),
+                _ => {}
+            }
+            for target in block.terminator.targets_mut() {
+                *target = name(target);
+            }
+            for statement in &mut block.statements {
+                for effect in &mut statement.effects {
+                    effect.rename(name);
+                }
+            }
+        }
+        input
+    }
+}
+
+impl Effect {
+    fn rename(&mut self, name: &dyn Fn(&str) -> String) {
+        match self {
+            Effect::Borrow { borrow, region, place, .. }
+            | Effect::Reserve { borrow, region, place } => {
+                *borrow = name(borrow);
+                *region = name(region);
+                if let Some(place) = place {
+                    place.rename(name);
+                }
+            }
+            Effect::Activate { borrow } | Effect::Kill { borrow } => *borrow = name(borrow),
+            Effect::LiveOnEntry { region } => *region = name(region),
+            Effect::Assign { place } | Effect::Read { place } | Effect::Write { place } => {
+                place.rename(name)
+            }
+            Effect::Outlives { a, b, .. } => {
+                *a = name(a);
+                *b = name(b);
+            }
+            Effect::Drop { variable } | Effect::Init { variable } | Effect::Move { variable } => {
+                *variable = name(variable)
+            }
+            Effect::Assert { assertion, .. } => match assertion {
+                Assertion::Live { borrow } | Assertion::Error { borrow } => *borrow = name(borrow),
+            },
+            Effect::Call { args, dest, .. } => {
+                for arg in args {
+                    *arg = name(arg);
+                }
+                if let Some(dest) = dest {
+                    *dest = name(dest);
+                }
+            }
+        }
+    }
+}
+
+impl Place {
+    /// Renames the local; field names are left alone.
+    fn rename(&mut self, name: &dyn Fn(&str) -> String) {
+        match self {
+            Place::Local(local) => *local = name(local),
+            Place::Deref(base) | Place::Field(base, _) => base.rename(name),
+        }
+    }
+}
@@ -0,0 +1,264 @@
+//! Minimizes an input while some predicate on it keeps holding, by
+//! greedily removing blocks, statements, effects, edges and
+//! variables one at a time (a simple form of delta debugging). This is
+//! meant for turning a large failing input into a small test case.
+
+use compare;
+use nll_souffle::ir::*;
+use nll_souffle::{solve, tab_delim};
+use std::env;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::process::{self, Command};
+
+crate enum Predicate {
+    /// `borrowLiveAt` contains `borrow` at `point`.
+    BorrowLiveAt { borrow: String, point: String },
+
+    /// `errors` contains `borrow` at `point`.
+    Error { borrow: String, point: String },
+
+    /// Souffle and our solver disagree on the result (see `compare`).
+    Disagree,
+
+    /// The shell command exits successfully when given the path of a
+    /// file containing the input as its last argument.
+    Command(String),
+}
+
+impl Predicate {
+    fn holds(&self, input: &Input) -> Result<bool, String> {
+        match self {
+            Predicate::BorrowLiveAt { borrow, point } => {
+                let (tables, output) = solve::solve_input(input);
+                Ok(output.borrow_live_at.iter().any(|&(b, p)| {
+                    tables.borrows.untern(b) == *borrow && tables.points.untern(p) == *point
+                }))
+            }
+
+            Predicate::Error { borrow, point } => {
+                let (tables, output) = solve::solve_input(input);
+                Ok(output.errors.iter().any(|&(b, p)| {
+                    tables.borrows.untern(b) == *borrow && tables.points.untern(p) == *point
+                }))
+            }
+
+            Predicate::Disagree => {
+                let facts_dir = env::temp_dir().join(format!("nll-reduce-{}", process::id()));
+                let result: Result<bool, Box<dyn Error>> = do catch {
+                    fs::create_dir_all(&facts_dir)?;
+                    tab_delim::write_tab_delimited_facts(input, &facts_dir)?;
+                    match compare::compare(&facts_dir)? {
+                        Some(differences) => Ok(!differences.is_empty()),
+                        None => Err("`--disagree` needs `souffle`, which is not installed".into()),
+                    }
+                };
+                let _ = fs::remove_dir_all(&facts_dir);
+                result.map_err(|err| err.to_string())
+            }
+
+            Predicate::Command(command) => {
+                let path = env::temp_dir().join(format!("nll-reduce-{}.txt", process::id()));
+                let result: Result<bool, ::std::io::Error> = do catch {
+                    File::create(&path)?.write_all(input.to_string().as_bytes())?;
+                    let status = Command::new("sh")
+                        .arg("-c")
+                        .arg(format!("{} \"$0\"", command))
+                        .arg(&path)
+                        .status()?;
+                    Ok(status.success())
+                };
+                let _ = fs::remove_file(&path);
+                result.map_err(|err| format!("running `{}`: {}", command, err))
+            }
+        }
+    }
+}
+
+/// Returns the smallest input we could find, starting from `input`,
+/// for which `predicate` holds. Comments are dropped.
+crate fn reduce(input: &Input, predicate: &Predicate) -> Result<Input, String> {
+    let mut reducer = Reducer {
+        predicate,
+        current: input.clone(),
+    };
+    reducer.strip_comments();
+    if !predicate.holds(&reducer.current)? {
+        return Err("the predicate does not hold for the input".to_string());
+    }
+
+    // Removing one thing often makes it possible to remove another
+    // that we already tried, so keep going until nothing changes.
+    loop {
+        let mut changed = false;
+        changed |= reducer.remove_blocks()?;
+        changed |= reducer.remove_statements()?;
+        changed |= reducer.remove_effects()?;
+        changed |= reducer.remove_gotos()?;
+        changed |= reducer.remove_variables()?;
+        if !changed {
+            return Ok(reducer.current);
+        }
+    }
+}
+
+struct Reducer<'p> {
+    predicate: &'p Predicate,
+    current: Input,
+}
+
+impl Reducer<'p> {
+    /// Makes `candidate` the current input if it is valid and the
+    /// predicate holds for it.
+    fn attempt(&mut self, candidate: Input) -> Result<bool, String> {
+        if candidate.validate().is_ok() && self.predicate.holds(&candidate)? {
+            self.current = candidate;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    fn strip_comments(&mut self) {
+        self.current.comments.clear();
+        for block in &mut self.current.blocks {
+            block.trailing_comments.clear();
+            for statement in &mut block.statements {
+                statement.comments.clear();
+            }
+        }
+    }
+
+    fn remove_blocks(&mut self) -> Result<bool, String> {
+        let mut changed = false;
+        let mut i = 0;
+        while i < self.current.blocks.len() {
+            let mut candidate = self.current.clone();
+            let block = candidate.blocks.remove(i);
+            for other in &mut candidate.blocks {
+                remove_edges_to(&mut other.terminator, &block.name);
+            }
+            if self.attempt(candidate)? {
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+        Ok(changed)
+    }
+
+    fn remove_statements(&mut self) -> Result<bool, String> {
+        let mut changed = false;
+        for i in 0..self.current.blocks.len() {
+            let mut j = 0;
+            while j < self.current.blocks[i].statements.len() {
+                let mut candidate = self.current.clone();
+                candidate.blocks[i].statements.remove(j);
+                if self.attempt(candidate)? {
+                    changed = true;
+                } else {
+                    j += 1;
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn remove_effects(&mut self) -> Result<bool, String> {
+        let mut changed = false;
+        for i in 0..self.current.blocks.len() {
+            for j in 0..self.current.blocks[i].statements.len() {
+                let mut k = 0;
+                while k < self.current.blocks[i].statements[j].effects.len() {
+                    let mut candidate = self.current.clone();
+                    candidate.blocks[i].statements[j].effects.remove(k);
+                    if self.attempt(candidate)? {
+                        changed = true;
+                    } else {
+                        k += 1;
+                    }
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn remove_gotos(&mut self) -> Result<bool, String> {
+        let mut changed = false;
+        for i in 0..self.current.blocks.len() {
+            let mut k = 0;
+            while k < num_edges(&self.current.blocks[i].terminator) {
+                let mut candidate = self.current.clone();
+                let removed = remove_edge(&mut candidate.blocks[i].terminator, k);
+                if removed && self.attempt(candidate)? {
+                    changed = true;
+                } else {
+                    k += 1;
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn remove_variables(&mut self) -> Result<bool, String> {
+        let mut changed = false;
+        let mut i = 0;
+        while i < self.current.variables.len() {
+            let mut candidate = self.current.clone();
+            candidate.variables.remove(i);
+            if self.attempt(candidate)? {
+                changed = true;
+            } else {
+                i += 1;
+            }
+        }
+        Ok(changed)
+    }
+}
+
+fn num_edges(terminator: &Terminator) -> usize {
+    terminator.successors().len() + terminator.unwind().map_or(0, |_| 1)
+}
+
+/// Removes the `k`th edge of `terminator`, counting the unwind edge
+/// last; returns false if that edge cannot be removed on its own (as
+/// is the case for the normal edge of a `call` or `drop`).
+fn remove_edge(terminator: &mut Terminator, k: usize) -> bool {
+    match terminator {
+        Terminator::Goto { targets } | Terminator::Switch { targets, .. } => {
+            targets.remove(k);
+            true
+        }
+        Terminator::Call { unwind, .. } | Terminator::Drop { unwind, .. } => {
+            if k == 1 {
+                *unwind = None;
+            }
+            k == 1
+        }
+        Terminator::Return | Terminator::Unreachable => false,
+    }
+}
+
+/// Removes all edges to `block`; a `call` or `drop` whose normal edge
+/// leads there becomes an empty `goto`.
+fn remove_edges_to(terminator: &mut Terminator, block: &str) {
+    let is_call_or_drop = match terminator {
+        Terminator::Call { .. } | Terminator::Drop { .. } => true,
+        _ => false,
+    };
+    if is_call_or_drop && terminator.successors().contains(&block) {
+        *terminator = Terminator::default();
+    }
+    match terminator {
+        Terminator::Goto { targets } | Terminator::Switch { targets, .. } => {
+            targets.retain(|target| target != block)
+        }
+        Terminator::Call { unwind, .. } | Terminator::Drop { unwind, .. } => {
+            if unwind.as_ref().map_or(false, |unwind| unwind == block) {
+                *unwind = None;
+            }
+        }
+        Terminator::Return | Terminator::Unreachable => {}
+    }
+}
@@ -0,0 +1,71 @@
+//! Snapshot tests: the results for `tests/<name>/test.txt` are checked
+//! against the files in `tests/<name>/expected/`, one per relation
+//! (`borrowLiveAt.txt` and so forth), each holding one tab-delimited
+//! tuple per line. A missing file stands for an empty relation.
+
+use nll_souffle::{json, solve};
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
+
+/// Solves `test_dir/test.txt` and returns a description of every
+/// difference from the expected results. If `bless` is true, the
+/// expected results are updated instead. Inputs that `solve` would
+/// reject are errors, and nothing is blessed for them.
+crate fn check_snapshot(test_dir: &Path, bless: bool) -> Result<Vec<String>, Box<dyn Error>> {
+    let program = json::read_program(&test_dir.join("test.txt"))?;
+
+    // (functions are namespaced, but otherwise solved as one input)
+    let (tables, output) = solve::solve_input(&program.merged());
+
+    let expected_dir = test_dir.join("expected");
+    let mut differences = vec![];
+    for (relation, tuples) in output.relations(&tables) {
+        let mut actual: Vec<String> = tuples.iter().map(|tuple| tuple.join("\t")).collect();
+        actual.sort();
+
+        let expected_path = expected_dir.join(format!("{}.txt", relation));
+        if bless {
+            if actual.is_empty() {
+                if expected_path.exists() {
+                    fs::remove_file(&expected_path)?;
+                }
+            } else {
+                fs::create_dir_all(&expected_dir)?;
+                let mut file = File::create(&expected_path)?;
+                for line in &actual {
+                    writeln!(file, "{}", line)?;
+                }
+            }
+            continue;
+        }
+
+        let mut expected: Vec<String> = vec![];
+        if expected_path.exists() {
+            let mut text = String::new();
+            File::open(&expected_path)?.read_to_string(&mut text)?;
+            expected = text.lines().map(|line| line.to_string()).collect();
+            expected.sort();
+        }
+
+        for line in expected.iter().filter(|line| !actual.contains(line)) {
+            differences.push(format!(
+                "`{}`: {} is missing `{}`",
+                test_dir.display(),
+                relation,
+                line.replace('\t', " "),
+            ));
+        }
+        for line in actual.iter().filter(|line| !expected.contains(line)) {
+            differences.push(format!(
+                "`{}`: {} has unexpected `{}`",
+                test_dir.display(),
+                relation,
+                line.replace('\t', " "),
+            ));
+        }
+    }
+
+    Ok(differences)
+}
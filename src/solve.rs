@@ -3,7 +3,9 @@
 use facts::*;
 use intern::{InternTo, InternerTables};
 use ir;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::Instant;
 
 use timely::{self, dataflow::*};
@@ -22,9 +24,54 @@ impl<E> PushInterned<E> for Vec<E> {
 }
 
 // This basically recreates what is in regions.dl
-crate fn region_computation(input: &ir::Input) {
+pub fn region_computation(input: &ir::Input) {
     let mut intern_tables = &mut InternerTables::new();
+    let all_facts = input_facts(input, intern_tables);
+    region_computation_from_facts(intern_tables, all_facts);
+}
+
+/// Lowers and solves `input`, without printing anything.
+pub fn solve_input(input: &ir::Input) -> (InternerTables, Output) {
+    let mut intern_tables = InternerTables::new();
+    let all_facts = input_facts(input, &mut intern_tables);
+    (intern_tables, solve(&all_facts))
+}
+
+/// Solves each function of `program` on its own, in parallel if
+/// `options.parallel` is set; the results are in the order of the
+/// functions.
+pub fn solve_program(
+    program: &ir::Program,
+    options: &SolveOptions,
+) -> Vec<(InternerTables, Output)> {
+    let options = *options;
+    let solve_function = move |input: &ir::Input| {
+        let mut intern_tables = InternerTables::new();
+        let all_facts = input_facts(input, &mut intern_tables);
+        (intern_tables, solve_with_options(&all_facts, &options))
+    };
+
+    if !options.parallel {
+        return program
+            .function_inputs()
+            .iter()
+            .map(solve_function)
+            .collect();
+    }
 
+    let threads: Vec<_> = program
+        .function_inputs()
+        .into_iter()
+        .map(|input| thread::spawn(move || solve_function(&input)))
+        .collect();
+    threads
+        .into_iter()
+        .map(|thread| thread.join().expect("solver thread panicked"))
+        .collect()
+}
+
+/// Lowers `input` into facts, interning all names into `intern_tables`.
+pub fn input_facts(input: &ir::Input, intern_tables: &mut InternerTables) -> AllFacts {
     macro_rules! collect_facts {
         (
             $input:expr,
@@ -42,7 +89,7 @@ crate fn region_computation(input: &ir::Input) {
         }
     }
 
-    let all_facts = AllFacts {
+    AllFacts {
         borrow_region: collect_facts!(
             input,
             for_each_borrow_region_fact,
@@ -64,6 +111,13 @@ crate fn region_computation(input: &ir::Input) {
             (p: Point, q: Point),
         ),
 
+        unwind_edge: collect_facts!(
+            input,
+            for_each_unwind_fact,
+            intern_tables,
+            (p: Point, q: Point),
+        ),
+
         region_live_on_entry: collect_facts!(
             input,
             for_each_region_live_on_entry_fact,
@@ -84,32 +138,283 @@ crate fn region_computation(input: &ir::Input) {
             intern_tables,
             (p: Point, a: Region, b: Region, q: Point),
         ),
-    };
 
-    region_computation_from_facts(intern_tables, all_facts);
+        var_drop: collect_facts!(
+            input,
+            for_each_var_drop_fact,
+            intern_tables,
+            (v: Variable, p: Point),
+        ),
+
+        var_drop_region: collect_facts!(
+            input,
+            for_each_var_drop_region_fact,
+            intern_tables,
+            (v: Variable, r: Region),
+        ),
+
+        var_init: collect_facts!(
+            input,
+            for_each_var_init_fact,
+            intern_tables,
+            (v: Variable, p: Point),
+        ),
+
+        var_moved: collect_facts!(
+            input,
+            for_each_var_moved_fact,
+            intern_tables,
+            (v: Variable, p: Point),
+        ),
+
+        borrow_reserved: collect_facts!(
+            input,
+            for_each_borrow_reserved_fact,
+            intern_tables,
+            (b: Borrow, p: Point),
+        ),
+
+        borrow_activated: collect_facts!(
+            input,
+            for_each_borrow_activated_fact,
+            intern_tables,
+            (b: Borrow, p: Point),
+        ),
+
+        mut_borrow: collect_facts!(
+            input,
+            for_each_mut_borrow_fact,
+            intern_tables,
+            (b: Borrow),
+        ),
+
+        borrowed_path_read: collect_facts!(
+            input,
+            for_each_borrowed_path_read_fact,
+            intern_tables,
+            (b: Borrow, p: Point),
+        ),
+
+        borrowed_path_written: collect_facts!(
+            input,
+            for_each_borrowed_path_written_fact,
+            intern_tables,
+            (b: Borrow, p: Point),
+        ),
+    }
+}
+
+/// The relations computed by the analysis.
+#[derive(Clone, Default)]
+pub struct Output {
+    pub region_live_at: Vec<(Region, Point)>,
+    pub points_to: Vec<(Region, Borrow, Point)>,
+    pub borrow_live_at: Vec<(Borrow, Point)>,
+    pub use_of_moved: Vec<(Variable, Point)>,
+    pub errors: Vec<(Borrow, Point)>,
+
+    /// With `SolveOptions::derive_liveness`, the tuples `(R, P, Q)`
+    /// where `R` is live at `Q`, the statement at `P` leads to `Q`, and
+    /// `R` is neither live at `P` nor assigned there; this usually means
+    /// that some `liveOnEntry(R)` was forgotten at `P`.
+    pub liveness_warnings: Vec<(Region, Point, Point)>,
 }
 
-crate fn region_computation_from_facts(intern_tables: &mut InternerTables, all_facts: AllFacts) {
+pub fn region_computation_from_facts(intern_tables: &mut InternerTables, all_facts: AllFacts) {
     let instant = Instant::now();
-    let borrow_live_at_vec: Arc<Mutex<Vec<(Borrow, Point)>>> = Arc::new(Mutex::new(Vec::new()));
-    push_timely_facts(all_facts, borrow_live_at_vec.clone());
+    let output = solve(&all_facts);
     let duration = instant.elapsed();
     println!("duration: {}.{:09}s", duration.as_secs(), duration.subsec_nanos());
+    output.dump(intern_tables);
+}
+
+/// Options for `solve_with_options` and `solve_program`.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SolveOptions {
+    /// Propagate the liveness of regions across unwind edges, as across
+    /// `goto` edges. By default (as in `regions.dl` without `-M
+    /// UNWIND_LIVENESS`), a region that is live on entry to a cleanup
+    /// block, or that a drop there needs, is not live before the edge.
+    pub unwind_liveness: bool,
+
+    /// Solve the functions of a program in parallel.
+    pub parallel: bool,
+
+    /// Derive the liveness of regions at the end of each block from
+    /// whatever is live at its successors, rather than only from their
+    /// `liveOnEntry` facts, and check the `liveOnEntry` facts within
+    /// blocks against the result (see `Output::liveness_warnings`).
+    pub derive_liveness: bool,
+}
+
+/// Runs the analysis on `all_facts`; the resulting relations are sorted.
+pub fn solve(all_facts: &AllFacts) -> Output {
+    solve_with_options(all_facts, &SolveOptions::default())
+}
 
-    println!("vvv borrowLiveAt vvv");
-    let mut vector = borrow_live_at_vec.lock().unwrap().clone();
-    vector.sort();
-    for (borrow, point) in vector {
-        println!(
-            "borrow {} live at {}",
-            intern_tables.borrows.untern(borrow),
-            intern_tables.points.untern(point),
-        );
+/// Like `solve`, with the given options.
+pub fn solve_with_options(all_facts: &AllFacts, options: &SolveOptions) -> Output {
+    let output: Arc<Mutex<Output>> = Arc::new(Mutex::new(Output::default()));
+    push_timely_facts(all_facts.clone(), output.clone(), *options);
+
+    let mut output = output.lock().unwrap().clone();
+    output.region_live_at.sort();
+    output.points_to.sort();
+    output.borrow_live_at.sort();
+    output.use_of_moved.sort();
+    output.errors.sort();
+    if options.derive_liveness {
+        output.liveness_warnings = liveness_warnings(all_facts, &output.region_live_at);
     }
-    println!("^^^ borrowLiveAt ^^^");
+    output
 }
 
-fn push_timely_facts(facts: AllFacts, borrow_live_at_vec: Arc<Mutex<Vec<(Borrow, Point)>>>) {
+/// See `Output::liveness_warnings`. A region is assigned at `P` if it
+/// is created by a borrow there, data flows into it there, or it is in
+/// the type of a variable initialized there.
+fn liveness_warnings(
+    all_facts: &AllFacts,
+    region_live_at: &[(Region, Point)],
+) -> Vec<(Region, Point, Point)> {
+    let live: HashSet<(Region, Point)> = region_live_at.iter().cloned().collect();
+    let mut assigned: HashSet<(Region, Point)> = HashSet::new();
+    assigned.extend(all_facts.borrow_region.iter().map(|&(r, _b, p)| (r, p)));
+    assigned.extend(all_facts.outlives.iter().map(|&(p, _a, b, _q)| (b, p)));
+    let mut drop_regions: HashMap<Variable, Vec<Region>> = HashMap::new();
+    for &(v, r) in &all_facts.var_drop_region {
+        drop_regions.entry(v).or_insert_with(Vec::new).push(r);
+    }
+    for &(v, p) in &all_facts.var_init {
+        if let Some(regions) = drop_regions.get(&v) {
+            assigned.extend(regions.iter().map(|&r| (r, p)));
+        }
+    }
+
+    let mut live_at_point: HashMap<Point, Vec<Region>> = HashMap::new();
+    for &(r, p) in region_live_at {
+        live_at_point.entry(p).or_insert_with(Vec::new).push(r);
+    }
+
+    let mut warnings = vec![];
+    for &(p, q) in &all_facts.next_statement {
+        if let Some(regions) = live_at_point.get(&q) {
+            for &r in regions {
+                if !live.contains(&(r, p)) && !assigned.contains(&(r, p)) {
+                    warnings.push((r, p, q));
+                }
+            }
+        }
+    }
+    warnings.sort();
+    warnings
+}
+
+impl Output {
+    /// Prints all relations, in the format the tests expect.
+    pub fn dump(&self, intern_tables: &InternerTables) {
+        println!("vvv borrowLiveAt vvv");
+        for &(borrow, point) in &self.borrow_live_at {
+            println!(
+                "borrow {} live at {}",
+                intern_tables.borrows.untern(borrow),
+                intern_tables.points.untern(point),
+            );
+        }
+        println!("^^^ borrowLiveAt ^^^");
+
+        println!("vvv useOfMoved vvv");
+        for &(variable, point) in &self.use_of_moved {
+            println!(
+                "variable {} used after move at {}",
+                intern_tables.variables.untern(variable),
+                intern_tables.points.untern(point),
+            );
+        }
+        println!("^^^ useOfMoved ^^^");
+
+        println!("vvv errors vvv");
+        for &(borrow, point) in &self.errors {
+            println!(
+                "borrow {} conflicts with access at {}",
+                intern_tables.borrows.untern(borrow),
+                intern_tables.points.untern(point),
+            );
+        }
+        println!("^^^ errors ^^^");
+    }
+
+    /// The output relations of `regions.dl`, by name, with each tuple
+    /// given as a list of names.
+    pub fn relations(
+        &self,
+        intern_tables: &InternerTables,
+    ) -> Vec<(&'static str, Vec<Vec<String>>)> {
+        let borrows_at = |tuples: &[(Borrow, Point)]| -> Vec<Vec<String>> {
+            tuples
+                .iter()
+                .map(|&(borrow, point)| {
+                    vec![
+                        intern_tables.borrows.untern(borrow).to_string(),
+                        intern_tables.points.untern(point).to_string(),
+                    ]
+                })
+                .collect()
+        };
+        let variables_at = |tuples: &[(Variable, Point)]| -> Vec<Vec<String>> {
+            tuples
+                .iter()
+                .map(|&(variable, point)| {
+                    vec![
+                        intern_tables.variables.untern(variable).to_string(),
+                        intern_tables.points.untern(point).to_string(),
+                    ]
+                })
+                .collect()
+        };
+        vec![
+            ("borrowLiveAt", borrows_at(&self.borrow_live_at)),
+            ("useOfMoved", variables_at(&self.use_of_moved)),
+            ("errors", borrows_at(&self.errors)),
+        ]
+    }
+
+    /// Like `relations`, but also with the intermediate relations that
+    /// we keep (`regionLiveAt` and `pointsTo`).
+    pub fn all_relations(
+        &self,
+        intern_tables: &InternerTables,
+    ) -> Vec<(&'static str, Vec<Vec<String>>)> {
+        let mut relations = self.relations(intern_tables);
+        relations.push((
+            "regionLiveAt",
+            self.region_live_at
+                .iter()
+                .map(|&(region, point)| {
+                    vec![
+                        intern_tables.regions.untern(region).to_string(),
+                        intern_tables.points.untern(point).to_string(),
+                    ]
+                })
+                .collect(),
+        ));
+        relations.push((
+            "pointsTo",
+            self.points_to
+                .iter()
+                .map(|&(region, borrow, point)| {
+                    vec![
+                        intern_tables.regions.untern(region).to_string(),
+                        intern_tables.borrows.untern(borrow).to_string(),
+                        intern_tables.points.untern(point).to_string(),
+                    ]
+                })
+                .collect(),
+        ));
+        relations
+    }
+}
+
+fn push_timely_facts(facts: AllFacts, output: Arc<Mutex<Output>>, options: SolveOptions) {
     timely::execute_from_args(vec![].into_iter(), {
         move |worker| {
             let probe = &mut ProbeHandle::new();
@@ -121,6 +426,16 @@ fn push_timely_facts(facts: AllFacts, borrow_live_at_vec: Arc<Mutex<Vec<(Borrow,
                 mut input_region_live_on_entry,
                 mut input_killed,
                 mut input_outlives,
+                mut input_var_drop,
+                mut input_var_drop_region,
+                mut input_var_init,
+                mut input_var_moved,
+                mut input_borrow_reserved,
+                mut input_borrow_activated,
+                mut input_mut_borrow,
+                mut input_borrowed_path_read,
+                mut input_borrowed_path_written,
+                mut input_unwind_edge,
             ) = worker.dataflow::<(), _, _>(|scope| {
                 // inputs to the computation
                 let (input_1, borrow_region) =
@@ -132,29 +447,197 @@ fn push_timely_facts(facts: AllFacts, borrow_live_at_vec: Arc<Mutex<Vec<(Borrow,
                 let (input_5, killed) = scope.new_collection::<(Borrow, Point), isize>();
                 let (input_6, outlives) =
                     scope.new_collection::<(Point, Region, Region, Point), isize>();
+                let (input_7, var_drop) = scope.new_collection::<(Variable, Point), isize>();
+                let (input_8, var_drop_region) =
+                    scope.new_collection::<(Variable, Region), isize>();
+                let (input_9, var_init) = scope.new_collection::<(Variable, Point), isize>();
+                let (input_10, var_moved) = scope.new_collection::<(Variable, Point), isize>();
+                let (input_11, borrow_reserved) = scope.new_collection::<(Borrow, Point), isize>();
+                let (input_12, borrow_activated) =
+                    scope.new_collection::<(Borrow, Point), isize>();
+                let (input_13, mut_borrow) = scope.new_collection::<Borrow, isize>();
+                let (input_14, borrowed_path_read) =
+                    scope.new_collection::<(Borrow, Point), isize>();
+                let (input_15, borrowed_path_written) =
+                    scope.new_collection::<(Borrow, Point), isize>();
+                let (input_16, unwind_edge) = scope.new_collection::<(Point, Point), isize>();
 
                 // cfgEdge(P, Q) :- nextStatement(P, Q).
                 // cfgEdge(P, Q) :- goto(P, Q).
-                let cfg_edge = next_statement.concat(&goto).distinct().probe_with(probe);
+                // cfgEdge(P, Q) :- unwindEdge(P, Q).
+                let cfg_edge = next_statement
+                    .concat(&goto)
+                    .concat(&unwind_edge)
+                    .distinct()
+                    .probe_with(probe);
+
+                // liveGoto(P, Q) :- goto(P, Q).
+                // liveGoto(P, Q) :- unwindEdge(P, Q).  (with `unwind_liveness`)
+                let live_goto = if options.unwind_liveness {
+                    goto.concat(&unwind_edge).distinct()
+                } else {
+                    goto.clone()
+                };
+
+                // .decl varMaybeInit( v:variable, p:point )
+                let var_maybe_init = {
+                    // varMaybeInit(V, Q) :-
+                    //   varInit(V, P),
+                    //   cfgEdge(P, Q).
+                    let var_maybe_init1 = var_init
+                        .map(|(v, p)| (p, v))
+                        .join(&cfg_edge)
+                        .map(|(_p, v, q)| (v, q));
+
+                    var_maybe_init1.iterate(|var_maybe_init| {
+                        let var_maybe_init1 = var_maybe_init1.enter(&var_maybe_init.scope());
+                        let var_moved = var_moved.enter(&var_maybe_init.scope());
+                        let cfg_edge = cfg_edge.enter(&var_maybe_init.scope());
+
+                        // varMaybeInit(V, Q) :-
+                        //   varMaybeInit(V, P),
+                        //   !varMoved(V, P),
+                        //   cfgEdge(P, Q).
+                        let var_maybe_init2 = var_maybe_init
+                            .map(|(v, p)| ((v, p), ()))
+                            .antijoin(&var_moved)
+                            .map(|((v, p), ())| (p, v))
+                            .join(&cfg_edge)
+                            .map(|(_p, v, q)| (v, q));
+
+                        var_maybe_init1.concat(&var_maybe_init2).distinct()
+                    })
+                };
+
+                // .decl varMaybeMoved( v:variable, p:point )
+                let var_maybe_moved = {
+                    // varMaybeMoved(V, Q) :-
+                    //   varMoved(V, P),
+                    //   cfgEdge(P, Q).
+                    let var_maybe_moved1 = var_moved
+                        .map(|(v, p)| (p, v))
+                        .join(&cfg_edge)
+                        .map(|(_p, v, q)| (v, q));
+
+                    var_maybe_moved1.iterate(|var_maybe_moved| {
+                        let var_maybe_moved1 = var_maybe_moved1.enter(&var_maybe_moved.scope());
+                        let var_init = var_init.enter(&var_maybe_moved.scope());
+                        let cfg_edge = cfg_edge.enter(&var_maybe_moved.scope());
+
+                        // varMaybeMoved(V, Q) :-
+                        //   varMaybeMoved(V, P),
+                        //   !varInit(V, P),
+                        //   cfgEdge(P, Q).
+                        let var_maybe_moved2 = var_maybe_moved
+                            .map(|(v, p)| ((v, p), ()))
+                            .antijoin(&var_init)
+                            .map(|((v, p), ())| (p, v))
+                            .join(&cfg_edge)
+                            .map(|(_p, v, q)| (v, q));
+
+                        var_maybe_moved1.concat(&var_maybe_moved2).distinct()
+                    })
+                };
+
+                // useOfMoved(V, P) :-
+                //   varMoved(V, P),
+                //   varMaybeMoved(V, P).
+                let use_of_moved = {
+                    let output = output.clone();
+                    var_moved
+                        .map(|(v, p)| ((v, p), ()))
+                        .semijoin(&var_maybe_moved)
+                        .map(|((v, p), ())| (v, p))
+                        .distinct()
+                        .inspect(move |&((v, p), _timestamp, _diff)| {
+                            output.lock().unwrap().use_of_moved.push((v, p));
+                        })
+                        .probe_with(probe)
+                };
+
+                // .decl varDropLive( v:variable, p:point )
+                let var_drop_live = {
+                    // varDropLive(V, P) :-
+                    //   varDrop(V, P),
+                    //   varMaybeInit(V, P).
+                    let var_drop_live1 = var_drop
+                        .map(|(v, p)| ((v, p), ()))
+                        .semijoin(&var_maybe_init)
+                        .map(|((v, p), ())| (v, p));
+
+                    var_drop_live1.iterate(|var_drop_live| {
+                        let var_drop_live1 = var_drop_live1.enter(&var_drop_live.scope());
+                        let var_init = var_init.enter(&var_drop_live.scope());
+                        let live_edge = next_statement
+                            .concat(&live_goto)
+                            .enter(&var_drop_live.scope());
+
+                        // varDropLive(V, P) :-
+                        //   nextStatement(P, Q),
+                        //   varDropLive(V, Q),
+                        //   !varInit(V, P).
+                        // varDropLive(V, P) :-
+                        //   liveGoto(P, Q),
+                        //   varDropLive(V, Q),
+                        //   !varInit(V, P).
+                        let var_drop_live2 = var_drop_live
+                            .map(|(v, q)| (q, v))
+                            .join(&live_edge.map(|(p, q)| (q, p)))
+                            .map(|(_q, v, p)| ((v, p), ()))
+                            .antijoin(&var_init)
+                            .map(|((v, p), ())| (v, p));
+
+                        var_drop_live1.concat(&var_drop_live2).distinct()
+                    })
+                };
+
+                // dropLive(R, P) :-
+                //   varDropLive(V, P),
+                //   varDropRegion(V, R).
+                let drop_live = var_drop_live
+                    .join_map(&var_drop_region, |_v, &p, &r| (r, p))
+                    .distinct();
 
                 // .decl regionLiveAt( r:region, p:point )
                 let region_live_at = {
                     // regionLiveAt(R, P) :- regionLiveOnEntryToStatement(R, P).
                     let region_live_at1 = region_live_on_entry.clone();
 
-                    // regionLiveAt(R, P) :-
-                    //   goto(P, Q),
-                    //   regionLiveOnEntryToStatement(R, Q).
-                    let region_live_at2 = {
-                        let goto_invert = goto.map(|(p, q)| (q, p));
-                        let region_live_on_entry_invert = region_live_on_entry.map(|(r, q)| (q, r));
-                        goto_invert.join_map(&region_live_on_entry_invert, |_q, &p, &r| (r, p))
-                    };
-
-                    region_live_at1
-                        .concat(&region_live_at2)
-                        .distinct()
-                        .probe_with(probe)
+                    // regionLiveAt(R, P) :- dropLive(R, P).
+                    let region_live_at3 = drop_live.clone();
+
+                    let goto_invert = live_goto.map(|(p, q)| (q, p));
+
+                    if options.derive_liveness {
+                        // regionLiveAt(R, P) :-
+                        //   liveGoto(P, Q),
+                        //   regionLiveAt(R, Q).
+                        let base = region_live_at1.concat(&region_live_at3);
+                        base.iterate(|region_live_at| {
+                            let base = base.enter(&region_live_at.scope());
+                            let goto_invert = goto_invert.enter(&region_live_at.scope());
+                            region_live_at
+                                .map(|(r, q)| (q, r))
+                                .join_map(&goto_invert, |_q, &r, &p| (r, p))
+                                .concat(&base)
+                                .distinct()
+                        }).probe_with(probe)
+                    } else {
+                        // regionLiveAt(R, P) :-
+                        //   liveGoto(P, Q),
+                        //   regionLiveOnEntryToStatement(R, Q).
+                        let region_live_at2 = {
+                            let region_live_on_entry_invert =
+                                region_live_on_entry.map(|(r, q)| (q, r));
+                            goto_invert.join_map(&region_live_on_entry_invert, |_q, &p, &r| (r, p))
+                        };
+
+                        region_live_at1
+                            .concat(&region_live_at2)
+                            .concat(&region_live_at3)
+                            .distinct()
+                            .probe_with(probe)
+                    }
                 };
 
                 // .decl restricts( r:region, b:borrow, p:point )
@@ -237,23 +720,124 @@ fn push_timely_facts(facts: AllFacts, borrow_live_at_vec: Arc<Mutex<Vec<(Borrow,
                              .inspect(|_| ())
                 });
 
+                {
+                    let output = output.clone();
+                    region_live_at
+                        .inspect(move |&((r, p), _timestamp, _diff)| {
+                            output.lock().unwrap().region_live_at.push((r, p));
+                        })
+                        .probe_with(probe);
+                }
+
+                {
+                    let output = output.clone();
+                    points_to
+                        .inspect(move |&((r, b, p), _timestamp, _diff)| {
+                            output.lock().unwrap().points_to.push((r, b, p));
+                        })
+                        .probe_with(probe);
+                }
+
                 // borrowLiveAt(B, P) :-
                 //   restricts(R, B, P)
                 //   regionLiveAt(R, P)
                 let borrow_live_at = {
-                    let borrow_live_at_vec = borrow_live_at_vec.clone();
+                    let output = output.clone();
                     restricts
                         .map(|(r, b, p)| ((r, p), b))
                         .semijoin(&region_live_at)
                         .map(|((r, p), b)| (b, p))
                         .distinct()
                         .inspect(move |&((b, p), _timestamp, _diff)| {
-                            borrow_live_at_vec.lock().unwrap().push((b, p));
+                            output.lock().unwrap().borrow_live_at.push((b, p));
+                        })
+                        .probe_with(probe)
+                };
+
+                // twoPhaseBorrow(B) :- borrowReserved(B, _P).
+                let two_phase_borrow = borrow_reserved.map(|(b, _p)| b).distinct();
+
+                // .decl borrowMaybeActivated( b:borrow, p:point )
+                let borrow_maybe_activated = borrow_activated.iterate(|borrow_maybe_activated| {
+                    let borrow_activated = borrow_activated.enter(&borrow_maybe_activated.scope());
+                    let cfg_edge = cfg_edge.enter(&borrow_maybe_activated.scope());
+                    let borrow_live_at = borrow_live_at.enter(&borrow_maybe_activated.scope());
+
+                    // borrowMaybeActivated(B, P) :- borrowActivated(B, P).
+                    let borrow_maybe_activated1 = borrow_activated.clone();
+
+                    // borrowMaybeActivated(B, Q) :-
+                    //   borrowMaybeActivated(B, P),
+                    //   cfgEdge(P, Q),
+                    //   borrowLiveAt(B, Q).
+                    let borrow_maybe_activated2 = borrow_maybe_activated
+                        .map(|(b, p)| (p, b))
+                        .join(&cfg_edge)
+                        .map(|(_p, b, q)| ((b, q), ()))
+                        .semijoin(&borrow_live_at)
+                        .map(|((b, q), ())| (b, q));
+
+                    borrow_maybe_activated1
+                        .concat(&borrow_maybe_activated2)
+                        .distinct()
+                });
+
+                // .decl borrowActiveAt( b:borrow, p:point )
+                let borrow_active_at = {
+                    // borrowActiveAt(B, P) :-
+                    //   borrowLiveAt(B, P),
+                    //   !twoPhaseBorrow(B).
+                    let borrow_active_at1 = borrow_live_at.antijoin(&two_phase_borrow);
+
+                    // borrowActiveAt(B, P) :-
+                    //   borrowLiveAt(B, P),
+                    //   borrowMaybeActivated(B, P).
+                    let borrow_active_at2 = borrow_live_at
+                        .map(|(b, p)| ((b, p), ()))
+                        .semijoin(&borrow_maybe_activated)
+                        .map(|((b, p), ())| (b, p));
+
+                    borrow_active_at1
+                        .concat(&borrow_active_at2)
+                        .distinct()
+                        .probe_with(probe)
+                };
+
+                // .decl errors( b:borrow, p:point )
+                let errors = {
+                    // errors(B, P) :-
+                    //   borrowedPathRead(B, P),
+                    //   mutBorrow(B),
+                    //   borrowActiveAt(B, P).
+                    let errors1 = borrowed_path_read
+                        .semijoin(&mut_borrow)
+                        .map(|(b, p)| ((b, p), ()))
+                        .semijoin(&borrow_active_at)
+                        .map(|((b, p), ())| (b, p));
+
+                    // errors(B, P) :-
+                    //   borrowedPathWritten(B, P),
+                    //   borrowLiveAt(B, P).
+                    let errors2 = borrowed_path_written
+                        .map(|(b, p)| ((b, p), ()))
+                        .semijoin(&borrow_live_at)
+                        .map(|((b, p), ())| (b, p));
+
+                    let output = output.clone();
+                    errors1
+                        .concat(&errors2)
+                        .distinct()
+                        .inspect(move |&((b, p), _timestamp, _diff)| {
+                            output.lock().unwrap().errors.push((b, p));
                         })
                         .probe_with(probe)
                 };
 
-                (input_1, input_2, input_3, input_4, input_5, input_6)
+                (
+                    input_1, input_2, input_3, input_4, input_5, input_6, input_7, input_8,
+                    input_9, input_10, input_11, input_12, input_13, input_14, input_15,
+                    input_16,
+                )
             });
 
             macro_rules! add_fact {
@@ -271,6 +855,16 @@ fn push_timely_facts(facts: AllFacts, borrow_live_at_vec: Arc<Mutex<Vec<(Borrow,
             add_fact!(input_region_live_on_entry, facts.region_live_on_entry);
             add_fact!(input_killed, facts.killed);
             add_fact!(input_outlives, facts.outlives);
+            add_fact!(input_var_drop, facts.var_drop);
+            add_fact!(input_var_drop_region, facts.var_drop_region);
+            add_fact!(input_var_init, facts.var_init);
+            add_fact!(input_var_moved, facts.var_moved);
+            add_fact!(input_borrow_reserved, facts.borrow_reserved);
+            add_fact!(input_borrow_activated, facts.borrow_activated);
+            add_fact!(input_mut_borrow, facts.mut_borrow);
+            add_fact!(input_borrowed_path_read, facts.borrowed_path_read);
+            add_fact!(input_borrowed_path_written, facts.borrowed_path_written);
+            add_fact!(input_unwind_edge, facts.unwind_edge);
         }
     }).unwrap();
 }
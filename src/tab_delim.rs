@@ -1,9 +1,11 @@
-use crate::facts::AllFacts;
+use crate::facts::{AllFacts, Borrow, Point, Region};
+use crate::error::InvalidInput;
 use crate::intern::{InternerTables, InternTo};
+use crate::ir::Input;
+use std::error::Error;
 use std::fs::File;
 use std::io::{self, prelude::*};
 use std::path::Path;
-use std::process;
 
 trait FromTabDelimited<'input>: Sized {
     fn parse(
@@ -12,37 +14,327 @@ trait FromTabDelimited<'input>: Sized {
     ) -> Option<Self>;
 }
 
-crate fn load_tab_delimited_facts(tables: &mut InternerTables, facts_dir: &Path) -> AllFacts {
-    AllFacts {
-        borrow_region: load_tab_delimited_file(tables, &facts_dir.join("borrowRegion.facts")).unwrap(),
-        next_statement: load_tab_delimited_file(tables, &facts_dir.join("nextStatement.facts")).unwrap(),
-        goto: load_tab_delimited_file(tables, &facts_dir.join("goto.facts")).unwrap(),
-        region_live_on_entry: load_tab_delimited_file(tables, &facts_dir.join("regionLiveOnEntryToStatement.facts")).unwrap(),
-        killed: load_tab_delimited_file(tables, &facts_dir.join("killed.facts")).unwrap(),
-        outlives: load_tab_delimited_file(tables, &facts_dir.join("outlives.facts")).unwrap(),
-    }
+/// Loads the facts in the layout written by `write_tab_delimited_facts`.
+/// The relations that were added after the first six may be missing
+/// (from directories written before they existed), and are then empty.
+pub fn load_tab_delimited_facts(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+) -> Result<AllFacts, Box<dyn Error>> {
+    Ok(AllFacts {
+        borrow_region: load_tab_delimited_file(tables, &facts_dir.join("borrowRegion.facts"))?,
+        next_statement: load_tab_delimited_file(tables, &facts_dir.join("nextStatement.facts"))?,
+        goto: load_tab_delimited_file(tables, &facts_dir.join("goto.facts"))?,
+        unwind_edge: load_optional_tab_delimited_file(tables, &facts_dir.join("unwindEdge.facts"))?,
+        region_live_on_entry: load_tab_delimited_file(tables, &facts_dir.join("regionLiveOnEntryToStatement.facts"))?,
+        killed: load_tab_delimited_file(tables, &facts_dir.join("killed.facts"))?,
+        outlives: load_tab_delimited_file(tables, &facts_dir.join("outlives.facts"))?,
+        var_drop: load_optional_tab_delimited_file(tables, &facts_dir.join("varDrop.facts"))?,
+        var_drop_region: load_optional_tab_delimited_file(tables, &facts_dir.join("varDropRegion.facts"))?,
+        var_init: load_optional_tab_delimited_file(tables, &facts_dir.join("varInit.facts"))?,
+        var_moved: load_optional_tab_delimited_file(tables, &facts_dir.join("varMoved.facts"))?,
+        borrow_reserved: load_optional_tab_delimited_file(tables, &facts_dir.join("borrowReserved.facts"))?,
+        borrow_activated: load_optional_tab_delimited_file(tables, &facts_dir.join("borrowActivated.facts"))?,
+        mut_borrow: load_optional_tab_delimited_file(tables, &facts_dir.join("mutBorrow.facts"))?,
+        borrowed_path_read: load_optional_tab_delimited_file(tables, &facts_dir.join("borrowedPathRead.facts"))?,
+        borrowed_path_written: load_optional_tab_delimited_file(tables, &facts_dir.join("borrowedPathWritten.facts"))?,
+    })
+}
+
+/// Loads the facts that rustc dumps with `-Znll-facts`. These use
+/// different file names, and two points per statement (e.g.,
+/// `Start(bb0[1])` and `Mid(bb0[1])`). The CFG comes as a single
+/// `cfg_edge` relation, which we split into `next_statement` and `goto`
+/// depending on whether the edge enters a new block; unwind edges are
+/// not told apart, and end up in `goto` as well.
+pub fn load_rustc_nll_facts(
+    tables: &mut InternerTables,
+    facts_dir: &Path,
+) -> Result<AllFacts, Box<dyn Error>> {
+    let cfg_edge: Vec<(Point, Point)> =
+        load_tab_delimited_file(tables, &facts_dir.join("cfg_edge.facts"))?;
+    let (goto, next_statement): (Vec<_>, Vec<_>) = cfg_edge
+        .into_iter()
+        .partition(|&(_p, q)| is_rustc_block_entry(tables.points.untern(q)));
+
+    // `outlives(R1, R2, P)` says that data flows from R1 into R2 at P.
+    let outlives: Vec<(Region, Region, Point)> =
+        load_tab_delimited_file(tables, &facts_dir.join("outlives.facts"))?;
+
+    // `invalidates(P, L)` says that P accesses the path borrowed by L in
+    // a way that conflicts with L; that is what writes do for us.
+    let invalidates: Vec<(Point, Borrow)> =
+        load_tab_delimited_file(tables, &facts_dir.join("invalidates.facts"))?;
+
+    Ok(AllFacts {
+        borrow_region: load_tab_delimited_file(tables, &facts_dir.join("borrow_region.facts"))?,
+        next_statement,
+        goto,
+        unwind_edge: vec![],
+        region_live_on_entry: load_tab_delimited_file(tables, &facts_dir.join("region_live_at.facts"))?,
+        killed: load_tab_delimited_file(tables, &facts_dir.join("killed.facts"))?,
+        outlives: outlives.into_iter().map(|(r1, r2, p)| (p, r1, r2, p)).collect(),
+        var_drop: vec![],
+        var_drop_region: vec![],
+        var_init: vec![],
+        var_moved: vec![],
+        borrow_reserved: vec![],
+        borrow_activated: vec![],
+        mut_borrow: vec![],
+        borrowed_path_read: vec![],
+        borrowed_path_written: invalidates.into_iter().map(|(p, b)| (b, p)).collect(),
+    })
+}
+
+/// True for the first point of a basic block, `Start(bbN[0])`.
+fn is_rustc_block_entry(point: &str) -> bool {
+    let point = point.trim_matches('"');
+    point.starts_with("Start(") && point.ends_with("[0])")
+}
+
+/// Writes the facts for `ir` into `facts_dir`, in the layout that
+/// `load_tab_delimited_facts` (and `regions.dl`) expect.
+pub fn write_tab_delimited_facts(ir: &Input, facts_dir: &Path) -> Result<(), Box<dyn Error>> {
+    write_to(&facts_dir.join("borrowRegion.facts"), |file| {
+        ir.for_each_borrow_region_fact(|region, borrow, point| {
+            write!(
+                file,
+                "\"{region}\"\t\"{borrow}\"\t\"{point}\"\n",
+                region = region,
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("nextStatement.facts"), |file| {
+        ir.for_each_next_statement_fact(|prev_point, point| {
+            write!(
+                file,
+                "\"{prev_point}\"\t\"{point}\"\n",
+                prev_point = prev_point,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("goto.facts"), |file| {
+        ir.for_each_goto_fact(|prev_point, point| {
+            write!(
+                file,
+                "\"{prev_point}\"\t\"{point}\"\n",
+                prev_point = prev_point,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("unwindEdge.facts"), |file| {
+        ir.for_each_unwind_fact(|prev_point, point| {
+            write!(
+                file,
+                "\"{prev_point}\"\t\"{point}\"\n",
+                prev_point = prev_point,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(
+        &facts_dir.join("regionLiveOnEntryToStatement.facts"),
+        |file| {
+            ir.for_each_region_live_on_entry_fact(|region, point| {
+                write!(
+                    file,
+                    "\"{region}\"\t\"{point}\"\n",
+                    region = region,
+                    point = point,
+                )
+            })?;
+            Ok(())
+        },
+    )?;
+
+    write_to(&facts_dir.join("killed.facts"), |file| {
+        ir.for_each_killed_fact(|borrow, point| {
+            write!(
+                file,
+                "\"{borrow}\"\t\"{point}\"\n",
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("outlives.facts"), |file| {
+        ir.for_each_outlives_fact(|p, a, b, q| {
+            write!(
+                file,
+                "\"{p}\"\t\"{a}\"\t\"{b}\"\t\"{q}\"\n",
+                p = p,
+                a = a,
+                b = b,
+                q = q,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("varDrop.facts"), |file| {
+        ir.for_each_var_drop_fact(|variable, point| {
+            write!(
+                file,
+                "\"{variable}\"\t\"{point}\"\n",
+                variable = variable,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("varDropRegion.facts"), |file| {
+        ir.for_each_var_drop_region_fact(|variable, region| {
+            write!(
+                file,
+                "\"{variable}\"\t\"{region}\"\n",
+                variable = variable,
+                region = region,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("varInit.facts"), |file| {
+        ir.for_each_var_init_fact(|variable, point| {
+            write!(
+                file,
+                "\"{variable}\"\t\"{point}\"\n",
+                variable = variable,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("varMoved.facts"), |file| {
+        ir.for_each_var_moved_fact(|variable, point| {
+            write!(
+                file,
+                "\"{variable}\"\t\"{point}\"\n",
+                variable = variable,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("borrowReserved.facts"), |file| {
+        ir.for_each_borrow_reserved_fact(|borrow, point| {
+            write!(
+                file,
+                "\"{borrow}\"\t\"{point}\"\n",
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("borrowActivated.facts"), |file| {
+        ir.for_each_borrow_activated_fact(|borrow, point| {
+            write!(
+                file,
+                "\"{borrow}\"\t\"{point}\"\n",
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("mutBorrow.facts"), |file| {
+        ir.for_each_mut_borrow_fact(|borrow| {
+            write!(file, "\"{borrow}\"\n", borrow = borrow)
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("borrowedPathRead.facts"), |file| {
+        ir.for_each_borrowed_path_read_fact(|borrow, point| {
+            write!(
+                file,
+                "\"{borrow}\"\t\"{point}\"\n",
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    write_to(&facts_dir.join("borrowedPathWritten.facts"), |file| {
+        ir.for_each_borrowed_path_written_fact(|borrow, point| {
+            write!(
+                file,
+                "\"{borrow}\"\t\"{point}\"\n",
+                borrow = borrow,
+                point = point,
+            )
+        })?;
+        Ok(())
+    })?;
+
+    Ok(())
 }
 
-fn load_tab_delimited_file<Row>(tables: &mut InternerTables, path: &Path) -> io::Result<Vec<Row>>
-    where Row: for<'input> FromTabDelimited<'input>
+/// Creates (or truncates) the file at `path` and writes `output` to it.
+pub fn write_to(
+    path: &Path,
+    output: impl FnOnce(&mut File) -> Result<(), Box<dyn Error>>,
+) -> Result<(), Box<dyn Error>> {
+    let mut file = File::create(path)?;
+    output(&mut file)?;
+    Ok(())
+}
+
+/// Loads one relation; I/O errors are `io::Error`s (naming `path`), and
+/// lines that do not parse are `InvalidInput`.
+fn load_tab_delimited_file<Row>(
+    tables: &mut InternerTables,
+    path: &Path,
+) -> Result<Vec<Row>, Box<dyn Error>>
+where
+    Row: for<'input> FromTabDelimited<'input>,
 {
-    let file = File::open(path)?;
+    let with_path =
+        |err: io::Error| io::Error::new(err.kind(), format!("`{}`: {}", path.display(), err));
+    let file = File::open(path).map_err(&with_path)?;
     let mut result = Vec::new();
     for (index, line) in io::BufReader::new(file).lines().enumerate() {
-        let line = line?;
+        let line = line.map_err(&with_path)?;
         let mut columns = line.split("\t");
         let row = match FromTabDelimited::parse(tables, &mut columns) {
             None => {
-                eprintln!("error parsing line {} of `{}`", index + 1, path.display());
-                process::exit(1);
+                return Err(Box::new(InvalidInput(format!(
+                    "error parsing line {} of `{}`",
+                    index + 1,
+                    path.display()
+                ))));
             }
 
             Some(v) => v,
         };
 
         if columns.next().is_some() {
-            eprintln!("extra data on line {} of `{}`", index + 1, path.display());
-            process::exit(1);
+            return Err(Box::new(InvalidInput(format!(
+                "extra data on line {} of `{}`",
+                index + 1,
+                path.display()
+            ))));
         }
 
         result.push(row);
@@ -50,6 +342,21 @@ fn load_tab_delimited_file<Row>(tables: &mut InternerTables, path: &Path) -> io:
     Ok(result)
 }
 
+/// Like `load_tab_delimited_file`, but a missing file is empty.
+fn load_optional_tab_delimited_file<Row>(
+    tables: &mut InternerTables,
+    path: &Path,
+) -> Result<Vec<Row>, Box<dyn Error>>
+where
+    Row: for<'input> FromTabDelimited<'input>,
+{
+    if path.exists() {
+        load_tab_delimited_file(tables, path)
+    } else {
+        Ok(vec![])
+    }
+}
+
 impl<T> FromTabDelimited<'input> for T
 where
     &'input str: InternTo<T>,
@@ -1,14 +1,32 @@
 #![cfg(test)]
 
 use assert_cli::Assert;
+use check;
+use facts_to_ir;
+use generate::{self, GenerateOptions};
+use nll_souffle::facts::{AllFacts, Borrow, Point, Region};
+use nll_souffle::intern::InternerTables;
+use nll_souffle::ir::{Effect, Place, Program, Terminator};
+use nll_souffle::json;
+use nll_souffle::lint;
+use nll_souffle::parser::{InputParser, ProgramParser};
+use nll_souffle::solve::{self, Output};
+use nll_souffle::InputBuilder;
+use reduce::{self, Predicate};
+use snapshot;
+use std::collections::HashSet;
+use std::env;
 use std::error::Error;
+use std::fs::{self, File};
+use std::io::prelude::*;
+use std::path::Path;
 
 macro_rules! run_souffle {
     (in ($test_dir:expr) expecting ($expected:expr)) => {
         const TEST_DIR: &str = $test_dir;
 
         Assert::main_binary()
-            .with_args(&[&format!("tests/{}/test.txt", TEST_DIR)])
+            .with_args(&["dump", &format!("tests/{}/test.txt", TEST_DIR)])
             .succeeds()
             .execute()?;
 
@@ -27,7 +45,7 @@ macro_rules! run_differential_dataflow {
         const TEST_DIR: &str = $test_dir;
 
         Assert::main_binary()
-            .with_args(&["--execute", &format!("tests/{}/test.txt", TEST_DIR)])
+            .with_args(&["solve", &format!("tests/{}/test.txt", TEST_DIR)])
             .stdout()
             .contains($expected)
             .unwrap();
@@ -122,3 +140,1134 @@ borrow B_x live at C/0
         )
     }
 }
+
+#[test]
+fn terminators_differential() {
+    // Liveness in the cleanup block does not flow back across the unwind
+    // edge, but the borrow still reaches the cleanup block.
+    Assert::main_binary()
+        .with_args(&["solve", "tests/terminators/test.txt"])
+        .stdout()
+        .doesnt_contain("borrow B_x live at B0/1")
+        .stdout()
+        .contains("borrow B_x live at B2/0")
+        .unwrap();
+}
+
+#[test]
+fn terminators_unwind_liveness() {
+    Assert::main_binary()
+        .with_args(&["solve", "--unwind-liveness", "tests/terminators/test.txt"])
+        .stdout()
+        .contains("borrow B_x live at B0/1")
+        .stdout()
+        .contains("borrow B_x live at B2/0")
+        .unwrap();
+}
+
+#[test]
+fn cleanup_drop() {
+    // The drop in the cleanup block does not make `G` live before the
+    // unwind edge, unless asked to.
+    Assert::main_binary()
+        .with_args(&["solve", "tests/cleanup-drop/test.txt"])
+        .stdout()
+        .doesnt_contain("borrow B_x live at B0/1")
+        .stdout()
+        .contains("borrow B_x live at B2/0")
+        .unwrap();
+
+    Assert::main_binary()
+        .with_args(&["solve", "--unwind-liveness", "tests/cleanup-drop/test.txt"])
+        .stdout()
+        .contains("borrow B_x live at B0/1")
+        .unwrap();
+}
+
+#[test]
+fn terminators_no_unwind() {
+    Assert::main_binary()
+        .with_args(&["solve", "--no-unwind", "tests/terminators/test.txt"])
+        .stdout()
+        .contains(
+            r#"vvv borrowLiveAt vvv
+^^^ borrowLiveAt ^^^
+"#,
+        )
+        .unwrap();
+}
+
+#[test]
+fn calls_differential() -> Result<(), Box<dyn Error>> {
+    // The same results as `issue-47680`, where the call is encoded by hand.
+    run_differential_dataflow! {
+        in("calls") expecting (
+            r#"vvv borrowLiveAt vvv
+borrow B_x live at B/1
+borrow B_x live at B/2
+borrow B_x live at C/0
+^^^ borrowLiveAt ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn issue_47680_annotated() {
+    Assert::main_binary()
+        .with_args(&["solve", "--annotate", "tests/issue-47680/test.txt"])
+        .stdout()
+        .contains(
+            r#"block A {
+  A/0: borrows { }, regions { }
+  A/1: borrows { }, regions { x }
+    goto { B }
+}
+
+block B {
+  B/0: borrows { }, regions { x }
+    liveOnEntry(x)
+    borrow(B_x as B_x)
+    post(B_x: temp0)
+  B/1: borrows { B_x }, regions { temp0 x }
+    liveOnEntry(temp0)
+    liveOnEntry(x)
+    pre(temp0: mn)
+    post(mn: match0)
+  B/2: borrows { B_x }, regions { match0 x }
+    goto { C D }
+}
+
+block C {
+  C/0: borrows { B_x }, regions { match0 }
+    liveOnEntry(match0)
+    kill(B_x)
+    post(match0: x)
+  C/1: borrows { }, regions { x }
+    goto { B }
+}
+
+block D {
+  D/0: borrows { }, regions { x }
+    liveOnEntry(x)
+  D/1: borrows { }, regions { x }
+    goto { B }
+}
+"#,
+        )
+        .unwrap();
+}
+
+#[test]
+fn issue_47680_json() {
+    Assert::main_binary()
+        .with_args(&["solve", "--format", "json", "tests/issue-47680/test.txt"])
+        .stdout()
+        .contains(r#""borrowLiveAt":[["B_x","B/1"],["B_x","B/2"],["B_x","C/0"]]"#)
+        .stdout()
+        .contains(r#""input":"tests/issue-47680/test.txt""#)
+        .stdout()
+        .contains(r#""counts":{"borrowLiveAt":3,"#)
+        .stdout()
+        .doesnt_contain("duration: ")
+        .unwrap();
+}
+
+#[test]
+fn json_lines_input() {
+    // `issue-47680` as a single function `bar`, in JSON
+    Assert::main_binary()
+        .with_args(&["solve", "tests/json/test.jsonl"])
+        .stdout()
+        .contains(
+            r#"--- fn bar ---
+vvv borrowLiveAt vvv
+borrow B_x live at B/1
+borrow B_x live at B/2
+borrow B_x live at C/0
+^^^ borrowLiveAt ^^^
+"#,
+        )
+        .unwrap();
+}
+
+#[test]
+fn inputs_are_validated() {
+    Assert::main_binary()
+        .with_args(&["solve", "tests/invalid/invalid.txt"])
+        .fails_with(2)
+        .stderr()
+        .contains("block `B0` jumps to undefined block `B1`")
+        .unwrap();
+}
+
+#[test]
+fn bless_rejects_invalid_inputs() {
+    Assert::main_binary()
+        .with_args(&["bless", "tests/invalid/snapshot"])
+        .fails_with(2)
+        .stderr()
+        .contains("block `B0` jumps to undefined block `B1`")
+        .unwrap();
+    assert!(!Path::new("tests/invalid/snapshot/expected").exists());
+}
+
+#[test]
+fn fmt_rejects_json() {
+    Assert::main_binary()
+        .with_args(&["fmt", "tests/json/test.jsonl"])
+        .fails_with(2)
+        .unwrap();
+}
+
+#[test]
+fn json_input_is_validated() {
+    let input = r#"{"blocks": [{"name": "B0", "terminator": {"goto": {"targets": ["B1"]}}}]}"#;
+    let err = json::parse_program(input).unwrap_err();
+    assert_eq!(err, "block `B0` jumps to undefined block `B1`");
+
+    // (the error is about the effect, not the shape of the whole file)
+    let input = r#"{"functions": [{"input": {"blocks": [{"name": "B0", "statements": [
+        {"effects": [{"borow": {"borrow": "B", "region": "R", "kind": "mut"}}]}
+    ]}]}}]}"#;
+    let err = json::parse_program(input).unwrap_err();
+    assert!(err.starts_with("unknown variant `borow`"), "{}", err);
+    assert!(err.contains("line 2"), "{}", err);
+
+    let lines = "\n{ \"input\": { \"blocks\": [] } }\n{ \"input\": 1 }\n";
+    let err = json::parse_functions(lines).unwrap_err();
+    assert!(err.starts_with("line 3: "), "{}", err);
+}
+
+#[test]
+fn functions_are_validated() -> Result<(), Box<dyn Error>> {
+    // All functions are solved together, so their names must tell them
+    // apart.
+    let program = ProgramParser::new()
+        .parse("fn foo { block B0 { } }\nfn foo { block B0 { } }\n")
+        .map_err(|err| err.to_string())?;
+    assert_eq!(program.validate(), Err("fn `foo` is defined twice".to_string()));
+
+    let lines = "{ \"input\": { \"blocks\": [] } }\n{ \"input\": { \"blocks\": [] } }\n";
+    let err = json::parse_functions(lines).unwrap_err();
+    assert_eq!(err, "functions must be named if there are several");
+
+    Ok(())
+}
+
+#[test]
+fn issue_47680_derived_liveness() {
+    // The results are the same, but `x` is live at the end of `A` (as it
+    // is live on entry to `B`), while nothing in `A` assigns it.
+    Assert::main_binary()
+        .with_args(&["solve", "--derive-liveness", "tests/issue-47680/test.txt"])
+        .stdout()
+        .contains(
+            r#"vvv borrowLiveAt vvv
+borrow B_x live at B/1
+borrow B_x live at B/2
+borrow B_x live at C/0
+^^^ borrowLiveAt ^^^
+"#,
+        )
+        .stderr()
+        .is("warning: region `x` is live at `A/1` but not at `A/0`, which does not assign it")
+        .unwrap();
+}
+
+#[test]
+fn drop_liveness() -> Result<(), Box<dyn Error>> {
+    // Only the borrow stored in the non-`may_dangle` variable is live.
+    run_souffle! {
+        in("drop-liveness") expecting (
+            r#"===============
+"B_y"	"B0/2"
+"B_y"	"B0/3"
+"B_y"	"B0/4"
+===============
+"#
+        )
+    }
+}
+
+#[test]
+fn drop_liveness_differential() -> Result<(), Box<dyn Error>> {
+    run_differential_dataflow! {
+        in("drop-liveness") expecting (
+            r#"vvv borrowLiveAt vvv
+borrow B_y live at B0/2
+borrow B_y live at B0/3
+borrow B_y live at B0/4
+^^^ borrowLiveAt ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn move_out() -> Result<(), Box<dyn Error>> {
+    run_souffle! {
+        in("move-out") expecting (
+            r#"useOfMoved
+===============
+"v"	"B0/3"
+===============
+"#
+        )
+    }
+}
+
+#[test]
+fn move_out_differential() -> Result<(), Box<dyn Error>> {
+    // The drop of the moved-out `v` does not keep `B_x` alive.
+    run_differential_dataflow! {
+        in("move-out") expecting (
+            r#"vvv borrowLiveAt vvv
+borrow B_x live at B0/1
+^^^ borrowLiveAt ^^^
+vvv useOfMoved vvv
+variable v used after move at B0/3
+^^^ useOfMoved ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn two_phase() -> Result<(), Box<dyn Error>> {
+    run_souffle! {
+        in("two-phase") expecting (
+            r#"errors
+===============
+"B_v"	"B2/0"
+===============
+"#
+        )
+    }
+}
+
+#[test]
+fn two_phase_differential() -> Result<(), Box<dyn Error>> {
+    // `B_v` is only active after the join on the path through `B1`.
+    run_differential_dataflow! {
+        in("two-phase") expecting (
+            r#"vvv errors vvv
+borrow B_v conflicts with access at B2/0
+^^^ errors ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn access_conflicts() -> Result<(), Box<dyn Error>> {
+    run_souffle! {
+        in("access-conflicts") expecting (
+            r#"errors
+===============
+"B_r"	"B0/4"
+"B_s"	"B0/8"
+===============
+"#
+        )
+    }
+}
+
+#[test]
+fn access_conflicts_differential() -> Result<(), Box<dyn Error>> {
+    // The read at B0/1 is fine since two-phase `B_v` is only reserved.
+    run_differential_dataflow! {
+        in("access-conflicts") expecting (
+            r#"vvv errors vvv
+borrow B_r conflicts with access at B0/4
+borrow B_s conflicts with access at B0/8
+^^^ errors ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn place_kills() -> Result<(), Box<dyn Error>> {
+    run_souffle! {
+        in("place-kills") expecting (
+            r#"errors
+===============
+"B_y"	"B0/4"
+"B_y"	"B0/5"
+===============
+"#
+        )
+    }
+}
+
+#[test]
+fn place_kills_differential() -> Result<(), Box<dyn Error>> {
+    // Overwriting `cursor` kills `B_c` without it being an error.
+    run_differential_dataflow! {
+        in("place-kills") expecting (
+            r#"vvv borrowLiveAt vvv
+borrow B_c live at B0/1
+borrow B_y live at B0/4
+borrow B_y live at B0/5
+borrow B_y live at B0/6
+^^^ borrowLiveAt ^^^
+vvv useOfMoved vvv
+^^^ useOfMoved ^^^
+vvv errors vvv
+borrow B_y conflicts with access at B0/4
+borrow B_y conflicts with access at B0/5
+^^^ errors ^^^
+"#
+        )
+    }
+}
+
+#[test]
+fn functions_differential() {
+    // Each function is reported separately, with its own names.
+    for args in &[
+        &["solve", "tests/functions/test.txt"][..],
+        &["solve", "--parallel", "tests/functions/test.txt"][..],
+    ] {
+        Assert::main_binary()
+            .with_args(*args)
+            .stdout()
+            .contains(
+                r#"--- fn foo ---
+vvv borrowLiveAt vvv
+borrow B_x live at B0/1
+^^^ borrowLiveAt ^^^
+"#,
+            )
+            .stdout()
+            .contains(
+                r#"--- fn bar ---
+vvv borrowLiveAt vvv
+borrow B_x live at B0/2
+^^^ borrowLiveAt ^^^
+"#,
+            )
+            .unwrap();
+    }
+}
+
+#[test]
+fn old_facts_differential() {
+    // Facts written before `unwindEdge`, `varDrop` and so forth existed;
+    // the missing relations are empty.
+    Assert::main_binary()
+        .with_args(&["solve-facts", "tests/old-facts"])
+        .stdout()
+        .contains(
+            r#"vvv borrowLiveAt vvv
+borrow "B" live at "A/1"
+^^^ borrowLiveAt ^^^
+"#,
+        )
+        .unwrap();
+}
+
+#[test]
+fn facts_errors() {
+    // A missing directory is an I/O error, and a line with too few
+    // columns makes the facts invalid; either way, we carry on.
+    Assert::main_binary()
+        .with_args(&["solve-facts", "tests/missing", "tests/malformed-facts", "tests/old-facts"])
+        .fails_with(3)
+        .stdout()
+        .contains(r#"borrow "B" live at "A/1""#)
+        .stderr()
+        .contains("error parsing line 1 of `tests/malformed-facts/borrowRegion.facts`")
+        .stderr()
+        .contains("1 of 3 inputs succeeded, 2 failed")
+        .unwrap();
+
+    Assert::main_binary()
+        .with_args(&["facts-to-ir", "tests/malformed-facts"])
+        .fails_with(2)
+        .unwrap();
+}
+
+#[test]
+fn rustc_facts_differential() -> Result<(), Box<dyn Error>> {
+    // Facts in the layout produced by rustc's `-Znll-facts`.
+    Assert::main_binary()
+        .with_args(&["solve-facts", "tests/rustc-facts"])
+        .stdout()
+        .contains(
+            r#"vvv borrowLiveAt vvv
+borrow "bw0" live at "Start(bb0[1])"
+borrow "bw0" live at "Mid(bb0[1])"
+^^^ borrowLiveAt ^^^
+"#,
+        )
+        .stdout()
+        .contains(
+            r#"vvv errors vvv
+borrow "bw0" conflicts with access at "Mid(bb0[1])"
+^^^ errors ^^^
+"#,
+        )
+        .unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn facts_to_ir() -> Result<(), Box<dyn Error>> {
+    Assert::main_binary()
+        .with_args(&["dump", "tests/facts-to-ir/test.txt"])
+        .succeeds()
+        .execute()?;
+
+    Assert::main_binary()
+        .with_args(&["facts-to-ir", "tests/facts-to-ir"])
+        .stdout()
+        .is(r#"// reconstructed from `tests/facts-to-ir`
+
+block A {
+  statement {
+    borrow(B0 as R0, shared)
+    post(R0: X)
+  }
+  goto { B C }
+}
+
+block B {
+  statement {
+    liveOnEntry(X)
+    pre(X: Y)
+    kill(B0)
+  }
+  goto { A }
+}
+
+block C {
+}
+"#)
+        .unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn facts_to_ir_round_trip() -> Result<(), Box<dyn Error>> {
+    // Reconstructing an input from its facts and lowering it again gives
+    // back the same facts, including those of variables, two-phase
+    // borrows and accesses of borrowed paths.
+    let ir = InputParser::new()
+        .parse(
+            r#"
+var v { V may_dangle(W) }
+
+block B0 {
+  statement {
+    init(v)
+    borrow(B_x as B_x, mut, x)
+    post(B_x: V)
+  }
+  statement {
+    liveOnEntry(V)
+    reserve(B_y as B_y, y)
+    read(x)
+  }
+  statement {
+    activate(B_y)
+    write(x)
+    assign(x)
+  }
+  statement {
+    move(v)
+    drop(v)
+  }
+  goto { B1 }
+}
+
+block B1 {
+  statement {
+    borrow(B_z as B_z, shared)
+    pre(B_z: V)
+  }
+}
+"#,
+        )
+        .map_err(|err| err.to_string())?;
+    let mut tables = InternerTables::new();
+    let all_facts = solve::input_facts(&ir, &mut tables);
+    let reconstructed = facts_to_ir::facts_to_ir(&tables, &all_facts)?;
+    let round_tripped = solve::input_facts(&reconstructed, &mut tables);
+
+    macro_rules! assert_same_facts {
+        ($($field:ident),*) => {
+            $(
+                let mut expected = all_facts.$field.clone();
+                expected.sort();
+                expected.dedup();
+                let mut actual = round_tripped.$field.clone();
+                actual.sort();
+                actual.dedup();
+                assert_eq!(expected, actual, "{} differ", stringify!($field));
+            )*
+        };
+    }
+    assert_same_facts!(
+        borrow_region,
+        next_statement,
+        goto,
+        unwind_edge,
+        region_live_on_entry,
+        killed,
+        outlives,
+        var_drop,
+        var_drop_region,
+        var_init,
+        var_moved,
+        borrow_reserved,
+        borrow_activated,
+        mut_borrow,
+        borrowed_path_read,
+        borrowed_path_written
+    );
+    assert!(!round_tripped.borrowed_path_written.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn facts_to_ir_output_parses() -> Result<(), Box<dyn Error>> {
+    // What is reconstructed from the facts of any of our inputs parses
+    // and is valid, even where names had to be renamed (as those of
+    // functions and calls are).
+    let parser = ProgramParser::new();
+    let mut reconstructed = HashSet::new();
+    for entry in fs::read_dir("tests")? {
+        let test_dir = entry?.path();
+        let path = test_dir.join("test.txt");
+        if !path.exists() {
+            continue;
+        }
+
+        let mut text = String::new();
+        File::open(&path)?.read_to_string(&mut text)?;
+        let program = parser.parse(&text).map_err(|err| err.to_string())?;
+        let mut tables = InternerTables::new();
+        let all_facts = solve::input_facts(&program.merged(), &mut tables);
+
+        // (unwind edges, for one, cannot be reconstructed)
+        let ir = match facts_to_ir::facts_to_ir(&tables, &all_facts) {
+            Ok(ir) => ir,
+            Err(_) => continue,
+        };
+        let reparsed = InputParser::new()
+            .parse(&ir.to_string())
+            .map_err(|err| format!("`{}`: {}", path.display(), err))?;
+        reparsed
+            .validate()
+            .map_err(|err| format!("`{}`: {}", path.display(), err))?;
+        reconstructed.insert(test_dir);
+    }
+    assert!(reconstructed.contains(Path::new("tests/functions")));
+
+    Ok(())
+}
+
+#[test]
+fn compare_engines() -> Result<(), Box<dyn Error>> {
+    Assert::main_binary()
+        .with_args(&["dump", "tests/access-conflicts/test.txt"])
+        .succeeds()
+        .execute()?;
+
+    Assert::main_binary()
+        .with_args(&["compare", "tests/access-conflicts"])
+        .stdout()
+        .is("`tests/access-conflicts`: souffle and differential-dataflow agree\n")
+        .unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn reduce_to_error() {
+    // The statements before the error are emptied rather than removed,
+    // as removing them would rename the point we are looking for.
+    Assert::main_binary()
+        .with_args(&["reduce", "--error", "B_y", "B0/5", "tests/place-kills/test.txt"])
+        .stdout()
+        .is(r#"block B0 {
+  statement {
+  }
+  statement {
+  }
+  statement {
+  }
+  statement {
+    borrow(B_y as B_y, mut, a.b)
+    post(B_y: Y)
+  }
+  statement {
+  }
+  statement {
+    liveOnEntry(Y)
+    read(a)
+  }
+}
+"#)
+        .unwrap();
+}
+
+#[test]
+fn reduce_keeps_input_valid() -> Result<(), Box<dyn Error>> {
+    // The predicate only looks for `drop(g)`, but `g` cannot be removed
+    // as long as it is dropped.
+    let mut text = String::new();
+    File::open("tests/drop-liveness/test.txt")?.read_to_string(&mut text)?;
+    let input = InputParser::new().parse(&text).map_err(|err| err.to_string())?;
+    let predicate = Predicate::Command("grep -q 'drop(g)'".to_string());
+    let reduced = reduce::reduce(&input, &predicate)?;
+
+    let reparsed = InputParser::new()
+        .parse(&reduced.to_string())
+        .map_err(|err| err.to_string())?;
+    reparsed.validate()?;
+    assert!(reparsed.variables.iter().any(|variable| variable.name == "g"));
+
+    Ok(())
+}
+
+#[test]
+fn reduce_function() {
+    Assert::main_binary()
+        .with_args(&[
+            "reduce",
+            "--function",
+            "bar",
+            "--live",
+            "B_x",
+            "B0/2",
+            "tests/functions/test.txt",
+        ])
+        .stdout()
+        .is(r#"block B0 {
+  statement {
+    borrow(B_x as B_x)
+    post(B_x: X)
+  }
+  statement {
+  }
+  statement {
+    liveOnEntry(X)
+  }
+}
+"#)
+        .unwrap();
+
+    Assert::main_binary()
+        .with_args(&["reduce", "--live", "B_x", "B0/2", "tests/functions/test.txt"])
+        .fails_with(2)
+        .unwrap();
+
+    // An input the predicate does not hold for cannot be reduced.
+    Assert::main_binary()
+        .with_args(&[
+            "reduce",
+            "--function",
+            "bar",
+            "--live",
+            "B_z",
+            "B0/0",
+            "tests/functions/test.txt",
+        ])
+        .fails_with(2)
+        .stderr()
+        .contains("the predicate does not hold for the input")
+        .unwrap();
+}
+
+#[test]
+fn check_assertions() {
+    Assert::main_binary()
+        .with_args(&["check", "tests/check/test.txt"])
+        .stdout()
+        .is("`tests/check/test.txt`: 3 of 3 assertions hold\n")
+        .unwrap();
+}
+
+#[test]
+fn check_failing_assertions() -> Result<(), Box<dyn Error>> {
+    let ir = InputParser::new()
+        .parse(
+            r#"
+block B0 {
+  statement {
+    borrow(B_x as B_x)
+    post(B_x: X)
+  }
+  statement {
+    assert !live(B_x)
+    assert error(B_x)
+    liveOnEntry(X)
+  }
+}
+"#,
+        )
+        .map_err(|err| err.to_string())?;
+    let (failures, num_assertions) = check::check(&ir);
+    let failures: Vec<String> = failures
+        .iter()
+        .map(|failure| format!("{}: {}", failure.point, failure.assertion))
+        .collect();
+    assert_eq!(failures, ["B0/1: assert !live(B_x)", "B0/1: assert error(B_x)"]);
+    assert_eq!(num_assertions, 2);
+
+    Ok(())
+}
+
+#[test]
+fn lint_finds_mistakes() {
+    Assert::main_binary()
+        .with_args(&["lint", "tests/lint/test.txt"])
+        .fails()
+        .stdout()
+        .is(
+            r#"`tests/lint/test.txt`: `x` is live on entry to `B0/1` and `B0/3`, but not to `B0/2`
+`tests/lint/test.txt`: `y` is live on entry to `B0/1`, but no borrow or outlives relation involves it
+"#,
+        )
+        .unwrap();
+}
+
+#[test]
+fn lint_destructor_regions() -> Result<(), Box<dyn Error>> {
+    // Dropping `g` needs `G`, but not the `may_dangle` region `V`.
+    let input = InputParser::new()
+        .parse(
+            r#"
+var g { G may_dangle(V) }
+
+block B0 {
+  statement {
+    liveOnEntry(G)
+    liveOnEntry(V)
+  }
+  statement {
+    drop(g)
+  }
+}
+"#,
+        )
+        .map_err(|err| err.to_string())?;
+    let lints: Vec<String> = lint::lint(&input).iter().map(|lint| lint.to_string()).collect();
+    assert_eq!(
+        lints,
+        vec!["`V` is live on entry to `B0/0`, but no borrow or outlives relation involves it"]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lint_clean() {
+    Assert::main_binary()
+        .with_args(&["lint", "tests/issue-47680/test.txt", "tests/calls/test.txt"])
+        .succeeds()
+        .unwrap();
+}
+
+#[test]
+fn dot_graph() {
+    Assert::main_binary()
+        .with_args(&["dot", "tests/terminators/test.txt"])
+        .stdout()
+        .is(r#"digraph "tests/terminators/test.txt" {
+  node [shape = box, fontname = monospace];
+  "B0" [label = "B0\lB0/0: borrow(B_x as B_x) post(B_x: X)\lB0/1: call(f) -> B1 unwind B2\l"];
+  "B1" [label = "B1\lB1/0:\lB1/1: return\l"];
+  "B2" [label = "B2\lB2/0: liveOnEntry(X)\lB2/1: return\l"];
+  "B0" -> "B1";
+  "B0" -> "B2" [style = dashed];
+}
+"#)
+        .unwrap();
+}
+
+#[test]
+fn cli_rejects_unknown_flags() {
+    Assert::main_binary()
+        .with_args(&["solve", "--bogus", "tests/check/test.txt"])
+        .fails_with(2)
+        .unwrap();
+
+    Assert::main_binary()
+        .with_args(&["solve", "--annotate", "--format", "json", "tests/check/test.txt"])
+        .fails_with(2)
+        .unwrap();
+}
+
+#[test]
+fn cli_continues_after_errors() {
+    // The missing file is an I/O error, which determines the exit status.
+    Assert::main_binary()
+        .with_args(&["check", "tests/missing/test.txt", "README.md", "tests/check/test.txt"])
+        .fails_with(3)
+        .stdout()
+        .is("`tests/check/test.txt`: 3 of 3 assertions hold\n")
+        .stderr()
+        .contains("1 of 3 inputs succeeded, 2 failed")
+        .unwrap();
+
+    // README.md does not parse
+    Assert::main_binary()
+        .with_args(&["check", "README.md", "tests/check/test.txt"])
+        .fails_with(2)
+        .unwrap();
+}
+
+#[test]
+fn builder_matches_parser() -> Result<(), Box<dyn Error>> {
+    let built = InputBuilder::new()
+        .var("v", &["V", "T"])
+        .may_dangle("T")
+        .block("B0")
+        .stmt(|s| s.init("v").borrow("B_x", "B_x").post("B_x", "V"))
+        .stmt(|s| {
+            s.live("V").effect(Effect::Read {
+                place: Place::Local("x".to_string()),
+            })
+        })
+        .goto(&["B1"])
+        .block("B1")
+        .stmt(|s| s.drop("v"))
+        .build()?;
+    let parsed = InputParser::new()
+        .parse(
+            r#"
+var v { V may_dangle(T) }
+
+block B0 {
+  statement {
+    init(v)
+    borrow(B_x as B_x)
+    post(B_x: V)
+  }
+  statement {
+    liveOnEntry(V)
+    read(x)
+  }
+  goto { B1 }
+}
+
+block B1 {
+  statement {
+    drop(v)
+  }
+}
+"#,
+        )
+        .map_err(|err| err.to_string())?;
+    assert_eq!(built, parsed);
+
+    Ok(())
+}
+
+#[test]
+fn builder_validates() {
+    let result = InputBuilder::new()
+        .block("B0")
+        .stmt(|s| s.live("X"))
+        .goto(&["B1"])
+        .build();
+    assert_eq!(
+        result.err(),
+        Some("block `B0` jumps to undefined block `B1`".to_string())
+    );
+
+    let result = InputBuilder::new()
+        .block("B0")
+        .stmt(|s| s.moved("v"))
+        .build();
+    assert_eq!(
+        result.err(),
+        Some("`move(v)` at `B0/0` uses undeclared variable `v`".to_string())
+    );
+
+    let result = InputBuilder::new()
+        .block("B0")
+        .stmt(|s| s.call("f", &["x"], Some("y")))
+        .build();
+    assert_eq!(
+        result.err(),
+        Some("`call(f, x -> y)` at `B0/0` calls `f`, which has no signature".to_string())
+    );
+
+    let result = InputBuilder::new()
+        .stmt(|s| s.live("X"))
+        .block("B0")
+        .build();
+    assert_eq!(result.err(), Some("`stmt` called before `block`".to_string()));
+
+    let result = InputBuilder::new()
+        .block("B0")
+        .terminator(Terminator::Return)
+        .goto(&["B0"])
+        .build();
+    assert_eq!(
+        result.err(),
+        Some("`goto` in `B0` cannot be combined with `return`".to_string())
+    );
+
+    let result = InputBuilder::new().var("v", &["V"]).may_dangle("W").build();
+    assert_eq!(
+        result.err(),
+        Some("`may_dangle`: variable `v` has no region `W`".to_string())
+    );
+}
+
+#[test]
+fn snapshots() -> Result<(), Box<dyn Error>> {
+    // Run with `BLESS=1` to update the expected results instead.
+    let bless = env::var_os("BLESS").is_some();
+    let mut differences = vec![];
+    for entry in fs::read_dir("tests")? {
+        let test_dir = entry?.path();
+        if test_dir.join("test.txt").exists() {
+            differences.extend(snapshot::check_snapshot(&test_dir, bless)?);
+        }
+    }
+    assert!(differences.is_empty(), "\n{}", differences.join("\n"));
+
+    Ok(())
+}
+
+#[test]
+fn pretty_print_round_trip() -> Result<(), Box<dyn Error>> {
+    // Printing any generated input, or any of our inputs, and parsing
+    // the result gives back the same input (modulo the order of
+    // effects), and printing is stable.
+    let input_parser = InputParser::new();
+    for seed in 0..100 {
+        let mut ir = generate::generate(seed, &GenerateOptions::default());
+        let printed = ir.to_string();
+        let reparsed = input_parser
+            .parse(&printed)
+            .map_err(|err| err.to_string())?;
+
+        ir.sort_effects();
+        assert_eq!(ir, reparsed, "seed {} does not round-trip", seed);
+        assert_eq!(printed, reparsed.to_string(), "seed {} is not stable", seed);
+    }
+
+    let parser = ProgramParser::new();
+    for entry in fs::read_dir("tests")? {
+        let path = entry?.path().join("test.txt");
+        if !path.exists() {
+            continue;
+        }
+
+        let mut text = String::new();
+        File::open(&path)?.read_to_string(&mut text)?;
+        let mut ir = parser.parse(&text).map_err(|err| err.to_string())?;
+        let printed = ir.to_string();
+        let reparsed = parser.parse(&printed).map_err(|err| err.to_string())?;
+
+        ir.sort_effects();
+        assert_eq!(ir, reparsed, "`{}` does not round-trip", path.display());
+        assert_eq!(printed, reparsed.to_string(), "`{}` is not stable", path.display());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn json_round_trip() -> Result<(), Box<dyn Error>> {
+    // Every one of our inputs can be written as JSON and read back.
+    let parser = ProgramParser::new();
+    for entry in fs::read_dir("tests")? {
+        let path = entry?.path().join("test.txt");
+        if !path.exists() {
+            continue;
+        }
+
+        let mut text = String::new();
+        File::open(&path)?.read_to_string(&mut text)?;
+        let ir = parser.parse(&text).map_err(|err| err.to_string())?;
+        let json = serde_json::to_string(&ir)?;
+        let reparsed: Program = serde_json::from_str(&json)?;
+        assert_eq!(ir, reparsed, "`{}` does not round-trip", path.display());
+    }
+
+    Ok(())
+}
+
+/// Generates an input from `seed` and solves it in-process, returning
+/// the facts along with the output.
+fn solve_generated(seed: u64) -> (InternerTables, AllFacts, Output) {
+    let input = generate::generate(seed, &GenerateOptions::default());
+    let mut tables = InternerTables::new();
+    let all_facts = solve::input_facts(&input, &mut tables);
+    let output = solve::solve(&all_facts);
+    (tables, all_facts, output)
+}
+
+#[test]
+fn generated_borrow_live_implies_points_to() {
+    // A borrow can only be live where some live region points to it.
+    for seed in 0..100 {
+        let (tables, _all_facts, output) = solve_generated(seed);
+        let region_live_at: HashSet<(Region, Point)> =
+            output.region_live_at.iter().cloned().collect();
+        let live_points_to: HashSet<(Borrow, Point)> = output
+            .points_to
+            .iter()
+            .filter(|&&(r, _b, p)| region_live_at.contains(&(r, p)))
+            .map(|&(_r, b, p)| (b, p))
+            .collect();
+        for &(b, p) in &output.borrow_live_at {
+            assert!(
+                live_points_to.contains(&(b, p)),
+                "seed {}: borrow {} live at {}, but no live region points to it",
+                seed,
+                tables.borrows.untern(b),
+                tables.points.untern(p),
+            );
+        }
+    }
+}
+
+#[test]
+fn generated_borrow_live_after_creation() {
+    // If `borrow(B as R)` is not killed right away, `B` is live at each
+    // successor where `R` is live.
+    for seed in 0..100 {
+        let (tables, all_facts, output) = solve_generated(seed);
+        let region_live_at: HashSet<(Region, Point)> =
+            output.region_live_at.iter().cloned().collect();
+        let borrow_live_at: HashSet<(Borrow, Point)> =
+            output.borrow_live_at.iter().cloned().collect();
+        let killed: HashSet<(Borrow, Point)> = all_facts.killed.iter().cloned().collect();
+        for &(r, b, p) in &all_facts.borrow_region {
+            if killed.contains(&(b, p)) {
+                continue;
+            }
+            let successors = all_facts
+                .next_statement
+                .iter()
+                .chain(&all_facts.goto)
+                .chain(&all_facts.unwind_edge)
+                .filter(|&&(p1, _q)| p1 == p)
+                .map(|&(_p, q)| q);
+            for q in successors {
+                if region_live_at.contains(&(r, q)) {
+                    assert!(
+                        borrow_live_at.contains(&(b, q)),
+                        "seed {}: borrow {} not live at {}",
+                        seed,
+                        tables.borrows.untern(b),
+                        tables.points.untern(q),
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Checks that inputs are well-formed before they are lowered, however
+//! they were constructed: parsed, read from JSON or built with
+//! `InputBuilder`.
+
+use ir::*;
+
+impl Program {
+    /// Checks that the program has some functions, that they are named
+    /// (uniquely) if there are several, that signatures with the same
+    /// name agree, and that each function is well-formed (see
+    /// `Input::validate`).
+    pub fn validate(&self) -> Result<(), String> {
+        if self.functions.is_empty() {
+            return Err("no functions".to_string());
+        }
+        if self.functions.len() > 1 {
+            for (index, function) in self.functions.iter().enumerate() {
+                let name = match &function.name {
+                    Some(name) => name,
+                    None => {
+                        return Err("functions must be named if there are several".to_string())
+                    }
+                };
+                if self.functions[..index].iter().any(|f| f.name.as_ref() == Some(name)) {
+                    return Err(format!("fn `{}` is defined twice", name));
+                }
+            }
+        }
+
+        // all functions are solved together (see `Program::merged`), so
+        // they must agree on the signatures they call
+        let mut signatures: Vec<&Signature> = self.signatures.iter().collect();
+        for function in &self.functions {
+            for signature in &function.input.signatures {
+                if signatures.iter().any(|s| s.name == signature.name && *s != signature) {
+                    return Err(format!(
+                        "signature `{}` is declared differently by several functions",
+                        signature.name
+                    ));
+                }
+                signatures.push(signature);
+            }
+        }
+
+        for (function, input) in self.functions.iter().zip(self.function_inputs()) {
+            input.validate().map_err(|err| match &function.name {
+                Some(name) => format!("fn {}: {}", name, err),
+                None => err,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+impl Input {
+    /// Checks that the input is well-formed: block and variable names
+    /// are unique, every terminator targets existing blocks, the
+    /// variables that are dropped, initialized or moved are declared,
+    /// and calls match the signature of their callee.
+    pub fn validate(&self) -> Result<(), String> {
+        for (index, signature) in self.signatures.iter().enumerate() {
+            if self.signatures[..index].iter().any(|s| s.name == signature.name) {
+                return Err(format!("signature `{}` is declared twice", signature.name));
+            }
+            for ty in signature.inputs.iter().chain(&signature.output) {
+                for lifetime in ty.lifetimes() {
+                    if lifetime != "static" && !signature.lifetimes.iter().any(|l| l == lifetime) {
+                        return Err(format!(
+                            "signature `{}` uses undeclared lifetime `'{}`",
+                            signature.name, lifetime
+                        ));
+                    }
+                }
+            }
+        }
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            if self.blocks[..index].iter().any(|b| b.name == block.name) {
+                return Err(format!("block `{}` is defined twice", block.name));
+            }
+        }
+
+        for (index, variable) in self.variables.iter().enumerate() {
+            if self.variables[..index].iter().any(|v| v.name == variable.name) {
+                return Err(format!("variable `{}` is declared twice", variable.name));
+            }
+        }
+
+        for block in &self.blocks {
+            let terminator = &block.terminator;
+            for target in terminator.successors().into_iter().chain(terminator.unwind()) {
+                if !self.blocks.iter().any(|b| b.name == target) {
+                    return Err(format!(
+                        "block `{}` jumps to undefined block `{}`",
+                        block.name, target
+                    ));
+                }
+            }
+
+            match terminator {
+                Terminator::Drop { variable, .. } => {
+                    if !self.variables.iter().any(|v| v.name == *variable) {
+                        return Err(format!(
+                            "`{}` in `{}` uses undeclared variable `{}`",
+                            terminator, block.name, variable
+                        ));
+                    }
+                }
+                Terminator::Call { callee, args, dest, .. } => {
+                    let at = format!("`{}` in `{}`", terminator, block.name);
+                    self.validate_call(callee, args, dest)
+                        .map_err(|err| format!("{} {}", at, err))?;
+                }
+                _ => {}
+            }
+
+            for (index, statement) in block.statements.iter().enumerate() {
+                for effect in &statement.effects {
+                    match effect {
+                        Effect::Drop { variable }
+                        | Effect::Init { variable }
+                        | Effect::Move { variable } => {
+                            if !self.variables.iter().any(|v| v.name == *variable) {
+                                return Err(format!(
+                                    "`{}` at `{}/{}` uses undeclared variable `{}`",
+                                    effect, block.name, index, variable
+                                ));
+                            }
+                        }
+                        Effect::Call { callee, args, dest } => {
+                            let at = format!("`{}` at `{}/{}`", effect, block.name, index);
+                            self.validate_call(callee, args, dest)
+                                .map_err(|err| format!("{} {}", at, err))?;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_call(
+        &self,
+        callee: &str,
+        args: &[String],
+        dest: &Option<String>,
+    ) -> Result<(), String> {
+        let signature = match self.signatures.iter().find(|s| s.name == callee) {
+            Some(signature) => signature,
+            None => return Err(format!("calls `{}`, which has no signature", callee)),
+        };
+        if args.len() != signature.inputs.len() {
+            return Err(format!(
+                "passes {} arguments to `{}`, which takes {}",
+                args.len(),
+                callee,
+                signature.inputs.len()
+            ));
+        }
+
+        // the regions of declared variables must match the lifetimes
+        // of their types one for one
+        let mut typed: Vec<(&str, &Type)> = args
+            .iter()
+            .map(|arg| arg.as_str())
+            .zip(&signature.inputs)
+            .collect();
+        if let (Some(dest), Some(output)) = (dest, &signature.output) {
+            typed.push((dest, output));
+        }
+        for (name, ty) in typed {
+            if let Some(variable) = self.variables.iter().find(|v| v.name == name) {
+                let num_lifetimes = ty.lifetimes().len();
+                if variable.regions.len() != num_lifetimes {
+                    return Err(format!(
+                        "gives `{}` (with {} regions) the type `{}` (with {} lifetimes)",
+                        name,
+                        variable.regions.len(),
+                        ty,
+                        num_lifetimes
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}